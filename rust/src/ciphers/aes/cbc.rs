@@ -5,41 +5,61 @@ use std::path::Path;
 
 use base64::decode as b64_decode;
 
-use aesni::block_cipher_trait::generic_array::GenericArray;
-use aesni::block_cipher_trait::BlockCipher;
-use aesni::Aes128;
-
+use super::Aes;
+use crate::ciphers::{padding_block_size, BlockCipher};
 use crate::utils::pad;
 use crate::utils::unpad;
 use crate::utils::xor;
+use crate::Error;
+use crate::Result;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-
-const BLOCK_SIZE: u8 = 16;
+fn check_iv(iv: &[u8], block_size: usize) -> Result<()> {
+    if iv.len() != block_size {
+        return Err(Error::InvalidIvLength(iv.len()));
+    }
+    Ok(())
+}
 
-pub fn decrypt_with_cipher<I>(data: &mut Vec<u8>, cipher: Aes128, iv: I)
+pub fn decrypt_blocks_with_cipher<C, I>(data: &mut [u8], cipher: C, iv: I) -> Result<()>
 where
+    C: BlockCipher,
     I: AsRef<[u8]>,
 {
-    let mut last_block = iv.as_ref().to_vec();
+    let iv = iv.as_ref();
+    let block_size = padding_block_size(&cipher)? as usize;
+    check_iv(iv, block_size)?;
+    if !data.len().is_multiple_of(block_size) {
+        return Err(Error::NotBlockMultiple(data.len()));
+    }
+
+    let mut last_block = iv.to_vec();
 
-    for mut block in data.chunks_exact_mut(BLOCK_SIZE as usize) {
+    for block in data.chunks_exact_mut(block_size) {
         let orig_block = block.to_vec();
-        cipher.decrypt_block(GenericArray::from_mut_slice(&mut block));
-        xor(&mut block, last_block);
+        cipher.decrypt_block(block);
+        xor(&mut *block, last_block);
         last_block = orig_block;
     }
-    unpad(data, BLOCK_SIZE);
+    Ok(())
+}
+
+pub fn decrypt_with_cipher<C, I>(data: &mut Vec<u8>, cipher: C, iv: I) -> Result<()>
+where
+    C: BlockCipher,
+    I: AsRef<[u8]>,
+{
+    let block_size = padding_block_size(&cipher)?;
+    decrypt_blocks_with_cipher(data, cipher, iv)?;
+    unpad(data, block_size)
 }
 
-pub fn decrypt<K, I>(data: &mut Vec<u8>, key: K, iv: I)
+pub fn decrypt<K, I>(data: &mut Vec<u8>, key: K, iv: I) -> Result<()>
 where
     K: AsRef<[u8]>,
     I: AsRef<[u8]>,
 {
-    let key = key.as_ref();
-    let cipher = Aes128::new(GenericArray::from_slice(key));
-    decrypt_with_cipher(data, cipher, iv);
+    let cipher = Aes::new(key)?;
+    decrypt_with_cipher(data, cipher, iv)
 }
 
 pub fn decrypt_file<P, K, I>(path: P, key: K, iv: I) -> Result<Vec<u8>>
@@ -52,35 +72,37 @@ where
 
     let mut output = b64_decode(
         file.bytes()
-            .map(|x| x.unwrap())
-            .filter(|&x| x != b'\n')
-            .collect::<Vec<u8>>(),
+            .filter(|x| !matches!(x, Ok(b'\n')))
+            .collect::<std::io::Result<Vec<u8>>>()?,
     )?;
 
-    decrypt(&mut output, key, iv);
+    decrypt(&mut output, key, iv)?;
     Ok(output)
 }
 
-pub fn encrypt_with_cipher<I>(data: &mut Vec<u8>, cipher: Aes128, iv: I)
+pub fn encrypt_with_cipher<C, I>(data: &mut Vec<u8>, cipher: C, iv: I) -> Result<()>
 where
+    C: BlockCipher,
     I: AsRef<[u8]>,
 {
     let mut last_block = iv.as_ref();
-    pad(data, BLOCK_SIZE);
+    let block_size = padding_block_size(&cipher)?;
+    check_iv(last_block, block_size as usize)?;
+    pad(data, block_size);
 
-    for mut block in data.chunks_exact_mut(BLOCK_SIZE as usize) {
-        xor(&mut block, last_block);
-        cipher.encrypt_block(GenericArray::from_mut_slice(&mut block));
+    for block in data.chunks_exact_mut(block_size as usize) {
+        xor(&mut *block, last_block);
+        cipher.encrypt_block(block);
         last_block = block;
     }
+    Ok(())
 }
 
-pub fn encrypt<K, I>(data: &mut Vec<u8>, key: K, iv: I)
+pub fn encrypt<K, I>(data: &mut Vec<u8>, key: K, iv: I) -> Result<()>
 where
     K: AsRef<[u8]>,
     I: AsRef<[u8]>,
 {
-    let key = key.as_ref();
-    let cipher = Aes128::new(GenericArray::from_slice(key));
-    encrypt_with_cipher(data, cipher, iv);
+    let cipher = Aes::new(key)?;
+    encrypt_with_cipher(data, cipher, iv)
 }
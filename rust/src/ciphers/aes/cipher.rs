@@ -0,0 +1,83 @@
+use aesni::block_cipher_trait::generic_array::GenericArray;
+use aesni::block_cipher_trait::BlockCipher as _;
+use aesni::{Aes128, Aes192, Aes256};
+
+use crate::ciphers::BlockCipher;
+use crate::Error;
+use crate::Result;
+
+const BLOCK_SIZE: usize = 16;
+
+macro_rules! impl_block_cipher {
+    ($cipher:ty) => {
+        impl BlockCipher for $cipher {
+            fn block_size(&self) -> usize {
+                BLOCK_SIZE
+            }
+
+            fn encrypt_block(&self, block: &mut [u8]) {
+                aesni::block_cipher_trait::BlockCipher::encrypt_block(
+                    self,
+                    GenericArray::from_mut_slice(block),
+                );
+            }
+
+            fn decrypt_block(&self, block: &mut [u8]) {
+                aesni::block_cipher_trait::BlockCipher::decrypt_block(
+                    self,
+                    GenericArray::from_mut_slice(block),
+                );
+            }
+        }
+    };
+}
+
+impl_block_cipher!(Aes128);
+impl_block_cipher!(Aes192);
+impl_block_cipher!(Aes256);
+
+// AES with the key size picked from the length of the key given
+#[derive(Clone, Copy)]
+pub enum Aes {
+    Aes128(Aes128),
+    Aes192(Aes192),
+    Aes256(Aes256),
+}
+
+impl Aes {
+    pub fn new<K>(key: K) -> Result<Aes>
+    where
+        K: AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+
+        match key.len() {
+            16 => Ok(Aes::Aes128(Aes128::new(GenericArray::from_slice(key)))),
+            24 => Ok(Aes::Aes192(Aes192::new(GenericArray::from_slice(key)))),
+            32 => Ok(Aes::Aes256(Aes256::new(GenericArray::from_slice(key)))),
+            i => Err(Error::InvalidKeyLength(i)),
+        }
+    }
+
+    fn inner(&self) -> &dyn BlockCipher {
+        match self {
+            Aes::Aes128(i) => i,
+            Aes::Aes192(i) => i,
+            Aes::Aes256(i) => i,
+        }
+    }
+}
+
+impl BlockCipher for Aes {
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        self.inner().encrypt_block(block)
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        self.inner().decrypt_block(block)
+    }
+}
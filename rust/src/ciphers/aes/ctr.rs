@@ -0,0 +1,167 @@
+use super::Aes;
+use crate::ciphers::BlockCipher;
+use crate::utils::xor;
+use crate::Error;
+use crate::Result;
+
+const BLOCK_SIZE: usize = 16;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Layout {
+    Nonce64Counter64LE, // 64bit little endian nonce then 64bit little endian counter (Cryptopals 18)
+    Nonce96Counter32BE, // 96bit nonce then 32bit big endian counter
+    Nonce32Counter96BE, // 32bit nonce then 96bit big endian counter
+}
+
+impl Layout {
+    pub fn nonce_size(self) -> usize {
+        match self {
+            Layout::Nonce64Counter64LE => 8,
+            Layout::Nonce96Counter32BE => 12,
+            Layout::Nonce32Counter96BE => 4,
+        }
+    }
+
+    // The last block counter that fits before it would wrap round and reuse
+    // keystream
+    pub fn max_counter(self) -> u64 {
+        match self {
+            Layout::Nonce96Counter32BE => u32::MAX as u64,
+            _ => u64::MAX,
+        }
+    }
+
+    fn counter_block(self, nonce: &[u8], counter: u64) -> [u8; BLOCK_SIZE] {
+        let nonce_size = self.nonce_size();
+        let mut output = [0; BLOCK_SIZE];
+        output[..nonce_size].copy_from_slice(nonce);
+
+        match self {
+            Layout::Nonce64Counter64LE => output[8..].copy_from_slice(&counter.to_le_bytes()),
+            Layout::Nonce96Counter32BE => {
+                output[12..].copy_from_slice(&(counter as u32).to_be_bytes())
+            }
+            Layout::Nonce32Counter96BE => output[8..].copy_from_slice(&counter.to_be_bytes()),
+        }
+        output
+    }
+}
+
+pub struct Keystream<C> {
+    cipher: C,
+    nonce: Vec<u8>,
+    layout: Layout,
+    position: u64,
+}
+
+impl<C> Keystream<C>
+where
+    C: BlockCipher,
+{
+    pub fn new_with_cipher<N>(cipher: C, nonce: N, layout: Layout) -> Result<Keystream<C>>
+    where
+        N: AsRef<[u8]>,
+    {
+        let nonce = nonce.as_ref();
+        if cipher.block_size() != BLOCK_SIZE {
+            return Err(Error::InvalidBlockSize(cipher.block_size()));
+        }
+        if nonce.len() != layout.nonce_size() {
+            return Err(Error::InvalidIvLength(nonce.len()));
+        }
+
+        Ok(Keystream {
+            cipher,
+            nonce: nonce.to_vec(),
+            layout,
+            position: 0,
+        })
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    pub fn block(&self, counter: u64) -> Result<[u8; BLOCK_SIZE]> {
+        if counter > self.layout.max_counter() {
+            return Err(Error::CounterOverflow(counter));
+        }
+
+        let mut output = self.layout.counter_block(&self.nonce, counter);
+        self.cipher.encrypt_block(&mut output);
+        Ok(output)
+    }
+
+    // Errors without touching the data if it would run past the last counter
+    pub fn apply<D>(&mut self, mut data: D) -> Result<()>
+    where
+        D: AsMut<[u8]>,
+    {
+        let mut data = data.as_mut();
+        if !data.is_empty() {
+            let end = self.position.checked_add(data.len() as u64);
+            let counter = end.map_or(u64::MAX, |x| (x - 1) / BLOCK_SIZE as u64);
+            if end.is_none() || counter > self.layout.max_counter() {
+                return Err(Error::CounterOverflow(counter));
+            }
+        }
+
+        while !data.is_empty() {
+            let counter = self.position / BLOCK_SIZE as u64;
+            let offset = (self.position % BLOCK_SIZE as u64) as usize;
+            let size = data.len().min(BLOCK_SIZE - offset);
+
+            let (chunk, rest) = data.split_at_mut(size);
+            xor(chunk, &self.block(counter)?[offset..]);
+            data = rest;
+            self.position += size as u64;
+        }
+        Ok(())
+    }
+}
+
+impl Keystream<Aes> {
+    pub fn new<K, N>(key: K, nonce: N, layout: Layout) -> Result<Keystream<Aes>>
+    where
+        K: AsRef<[u8]>,
+        N: AsRef<[u8]>,
+    {
+        let cipher = Aes::new(key)?;
+        Keystream::new_with_cipher(cipher, nonce, layout)
+    }
+}
+
+impl<C> Iterator for Keystream<C>
+where
+    C: BlockCipher,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let mut output = [0];
+        self.apply(&mut output).ok()?;
+        Some(output[0])
+    }
+}
+
+pub fn crypt_with_cipher<D, C, N>(data: D, cipher: C, nonce: N, layout: Layout) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    C: BlockCipher,
+    N: AsRef<[u8]>,
+{
+    Keystream::new_with_cipher(cipher, nonce, layout)?.apply(data)
+}
+
+pub fn crypt<D, K, N>(data: D, key: K, nonce: N, layout: Layout) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+    N: AsRef<[u8]>,
+{
+    Keystream::new(key, nonce, layout)?.apply(data)
+}
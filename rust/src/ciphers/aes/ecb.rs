@@ -5,31 +5,34 @@ use std::path::Path;
 
 use base64::decode as b64_decode;
 
-use aesni::block_cipher_trait::generic_array::GenericArray;
-use aesni::block_cipher_trait::BlockCipher;
-use aesni::Aes128;
-
+use super::Aes;
+use crate::ciphers::{padding_block_size, BlockCipher};
 use crate::utils::pad;
 use crate::utils::unpad;
+use crate::Error;
+use crate::Result;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-
-const BLOCK_SIZE: u8 = 16;
+pub fn decrypt_with_cipher<C>(data: &mut Vec<u8>, cipher: C) -> Result<()>
+where
+    C: BlockCipher,
+{
+    let block_size = padding_block_size(&cipher)?;
+    if !data.len().is_multiple_of(block_size as usize) {
+        return Err(Error::NotBlockMultiple(data.len()));
+    }
 
-pub fn decrypt_with_cipher(data: &mut Vec<u8>, cipher: Aes128) {
-    for mut block in data.chunks_exact_mut(BLOCK_SIZE as usize) {
-        cipher.decrypt_block(GenericArray::from_mut_slice(&mut block));
+    for block in data.chunks_exact_mut(block_size as usize) {
+        cipher.decrypt_block(block);
     }
-    unpad(data, BLOCK_SIZE);
+    unpad(data, block_size)
 }
 
-pub fn decrypt<K>(data: &mut Vec<u8>, key: K)
+pub fn decrypt<K>(data: &mut Vec<u8>, key: K) -> Result<()>
 where
     K: AsRef<[u8]>,
 {
-    let key = key.as_ref();
-    let cipher = Aes128::new(GenericArray::from_slice(key));
-    decrypt_with_cipher(data, cipher);
+    let cipher = Aes::new(key)?;
+    decrypt_with_cipher(data, cipher)
 }
 
 pub fn decrypt_file<P, K>(path: P, key: K) -> Result<Vec<u8>>
@@ -41,27 +44,30 @@ where
 
     let mut output = b64_decode(
         file.bytes()
-            .map(|x| x.unwrap())
-            .filter(|&x| x != b'\n')
-            .collect::<Vec<u8>>(),
+            .filter(|x| !matches!(x, Ok(b'\n')))
+            .collect::<std::io::Result<Vec<u8>>>()?,
     )?;
 
-    decrypt(&mut output, key);
+    decrypt(&mut output, key)?;
     Ok(output)
 }
 
-pub fn encrypt_with_cipher(data: &mut Vec<u8>, cipher: Aes128) {
-    pad(data, BLOCK_SIZE);
-    for mut block in data.chunks_exact_mut(BLOCK_SIZE as usize) {
-        cipher.encrypt_block(GenericArray::from_mut_slice(&mut block));
+pub fn encrypt_with_cipher<C>(data: &mut Vec<u8>, cipher: C) -> Result<()>
+where
+    C: BlockCipher,
+{
+    let block_size = padding_block_size(&cipher)?;
+    pad(data, block_size);
+    for block in data.chunks_exact_mut(block_size as usize) {
+        cipher.encrypt_block(block);
     }
+    Ok(())
 }
 
-pub fn encrypt<K>(data: &mut Vec<u8>, key: K)
+pub fn encrypt<K>(data: &mut Vec<u8>, key: K) -> Result<()>
 where
     K: AsRef<[u8]>,
 {
-    let key = key.as_ref();
-    let cipher = Aes128::new(GenericArray::from_slice(key));
-    encrypt_with_cipher(data, cipher);
+    let cipher = Aes::new(key)?;
+    encrypt_with_cipher(data, cipher)
 }
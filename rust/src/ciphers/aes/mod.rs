@@ -1,5 +1,10 @@
 #[cfg(test)]
 mod tests;
 
+mod cipher;
+pub use cipher::*;
+
 pub mod cbc;
+pub mod ctr;
 pub mod ecb;
+pub mod soft;
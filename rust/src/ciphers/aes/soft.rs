@@ -0,0 +1,266 @@
+use crate::ciphers::BlockCipher;
+use crate::Error;
+use crate::Result;
+
+pub const BLOCK_SIZE: usize = 16;
+
+pub type State = [u8; BLOCK_SIZE];
+
+pub const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+pub const INV_SBOX: [u8; 256] = [
+    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb,
+    0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44, 0xc4, 0xde, 0xe9, 0xcb,
+    0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c, 0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e,
+    0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2, 0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25,
+    0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92,
+    0x6c, 0x70, 0x48, 0x50, 0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84,
+    0x90, 0xd8, 0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
+    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13, 0x8a, 0x6b,
+    0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce, 0xf0, 0xb4, 0xe6, 0x73,
+    0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9, 0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e,
+    0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89, 0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b,
+    0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2, 0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4,
+    0x1f, 0xdd, 0xa8, 0x33, 0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f,
+    0x60, 0x51, 0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
+    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53, 0x99, 0x61,
+    0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
+];
+
+// Multiply by x (ie: {02}) in GF(2^8) using the AES polynomial
+pub fn xtime(a: u8) -> u8 {
+    let output = a << 1;
+    if a & 0x80 != 0 {
+        output ^ 0x1b
+    } else {
+        output
+    }
+}
+
+pub fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut output = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            output ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    output
+}
+
+pub fn sub_bytes(state: &mut State) {
+    for a in state.iter_mut() {
+        *a = SBOX[*a as usize];
+    }
+}
+
+pub fn inv_sub_bytes(state: &mut State) {
+    for a in state.iter_mut() {
+        *a = INV_SBOX[*a as usize];
+    }
+}
+
+// The state is column major (as in FIPS-197), byte index = row + 4 * column
+pub fn shift_rows(state: &mut State) {
+    let orig = *state;
+    for row in 1..4 {
+        for column in 0..4 {
+            state[row + 4 * column] = orig[row + 4 * ((column + row) % 4)];
+        }
+    }
+}
+
+pub fn inv_shift_rows(state: &mut State) {
+    let orig = *state;
+    for row in 1..4 {
+        for column in 0..4 {
+            state[row + 4 * ((column + row) % 4)] = orig[row + 4 * column];
+        }
+    }
+}
+
+pub fn mix_columns(state: &mut State) {
+    for column in state.chunks_exact_mut(4) {
+        let [a, b, c, d] = [column[0], column[1], column[2], column[3]];
+        column[0] = xtime(a) ^ xtime(b) ^ b ^ c ^ d;
+        column[1] = a ^ xtime(b) ^ xtime(c) ^ c ^ d;
+        column[2] = a ^ b ^ xtime(c) ^ xtime(d) ^ d;
+        column[3] = xtime(a) ^ a ^ b ^ c ^ xtime(d);
+    }
+}
+
+pub fn inv_mix_columns(state: &mut State) {
+    for column in state.chunks_exact_mut(4) {
+        let [a, b, c, d] = [column[0], column[1], column[2], column[3]];
+        column[0] = gf_mul(a, 14) ^ gf_mul(b, 11) ^ gf_mul(c, 13) ^ gf_mul(d, 9);
+        column[1] = gf_mul(a, 9) ^ gf_mul(b, 14) ^ gf_mul(c, 11) ^ gf_mul(d, 13);
+        column[2] = gf_mul(a, 13) ^ gf_mul(b, 9) ^ gf_mul(c, 14) ^ gf_mul(d, 11);
+        column[3] = gf_mul(a, 11) ^ gf_mul(b, 13) ^ gf_mul(c, 9) ^ gf_mul(d, 14);
+    }
+}
+
+pub fn add_round_key(state: &mut State, round_key: &State) {
+    for (a, b) in state.iter_mut().zip(round_key.iter()) {
+        *a ^= b;
+    }
+}
+
+pub fn default_rounds(key_size: usize) -> Result<usize> {
+    match key_size {
+        16 => Ok(10),
+        24 => Ok(12),
+        32 => Ok(14),
+        i => Err(Error::InvalidKeyLength(i)),
+    }
+}
+
+// Returns rounds + 1 round keys, continuing the schedule past the standard
+// number of rounds if asked to
+pub fn expand_key<K>(key: K, rounds: usize) -> Result<Vec<State>>
+where
+    K: AsRef<[u8]>,
+{
+    let key = key.as_ref();
+    default_rounds(key.len())?;
+
+    let key_words = key.len() / 4;
+    let total_words = 4 * (rounds + 1);
+
+    let mut words: Vec<[u8; 4]> = key
+        .chunks_exact(4)
+        .map(|x| [x[0], x[1], x[2], x[3]])
+        .collect();
+
+    let mut rcon = 1;
+    for i in key_words..total_words {
+        let mut word = words[i - 1];
+        if i % key_words == 0 {
+            word.rotate_left(1);
+            for a in word.iter_mut() {
+                *a = SBOX[*a as usize];
+            }
+            word[0] ^= rcon;
+            rcon = xtime(rcon);
+        } else if key_words > 6 && i % key_words == 4 {
+            for a in word.iter_mut() {
+                *a = SBOX[*a as usize];
+            }
+        }
+
+        for (a, b) in word.iter_mut().zip(words[i - key_words].iter()) {
+            *a ^= b;
+        }
+        words.push(word);
+    }
+
+    Ok(words
+        .chunks_exact(4)
+        .take(rounds + 1)
+        .map(|x| {
+            let mut round_key = [0; BLOCK_SIZE];
+            for (i, word) in x.iter().enumerate() {
+                round_key[4 * i..4 * i + 4].copy_from_slice(word);
+            }
+            round_key
+        })
+        .collect())
+}
+
+#[derive(Clone)]
+pub struct SoftAes {
+    round_keys: Vec<State>,
+}
+
+impl SoftAes {
+    pub fn new<K>(key: K) -> Result<SoftAes>
+    where
+        K: AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+        SoftAes::with_rounds(key, default_rounds(key.len())?)
+    }
+
+    // Reduced (or extended) round AES for playing with round attacks
+    pub fn with_rounds<K>(key: K, rounds: usize) -> Result<SoftAes>
+    where
+        K: AsRef<[u8]>,
+    {
+        Ok(SoftAes {
+            round_keys: expand_key(key, rounds)?,
+        })
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.round_keys.len() - 1
+    }
+
+    pub fn round_keys(&self) -> &[State] {
+        &self.round_keys
+    }
+
+    pub fn encrypt_state(&self, state: &mut State) {
+        let rounds = self.rounds();
+
+        add_round_key(state, &self.round_keys[0]);
+        for round in 1..=rounds {
+            sub_bytes(state);
+            shift_rows(state);
+            if round != rounds {
+                mix_columns(state);
+            }
+            add_round_key(state, &self.round_keys[round]);
+        }
+    }
+
+    pub fn decrypt_state(&self, state: &mut State) {
+        let rounds = self.rounds();
+
+        for round in (1..=rounds).rev() {
+            add_round_key(state, &self.round_keys[round]);
+            if round != rounds {
+                inv_mix_columns(state);
+            }
+            inv_shift_rows(state);
+            inv_sub_bytes(state);
+        }
+        add_round_key(state, &self.round_keys[0]);
+    }
+}
+
+impl BlockCipher for SoftAes {
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        let mut state = [0; BLOCK_SIZE];
+        state.copy_from_slice(block);
+        self.encrypt_state(&mut state);
+        block.copy_from_slice(&state);
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        let mut state = [0; BLOCK_SIZE];
+        state.copy_from_slice(block);
+        self.decrypt_state(&mut state);
+        block.copy_from_slice(&state);
+    }
+}
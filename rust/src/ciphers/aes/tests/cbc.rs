@@ -2,6 +2,9 @@ use super::super::cbc::*;
 
 use rand::prelude::*;
 
+use crate::ciphers::BlockCipher;
+use crate::Error;
+
 const BLOCK_SIZE: usize = 16;
 
 #[test]
@@ -13,10 +16,56 @@ fn test() {
         let iv = rng.gen::<[u8; BLOCK_SIZE]>();
         let mut result = input.clone();
 
-        encrypt(&mut result, key, iv);
+        encrypt(&mut result, key, iv).unwrap();
         assert_ne!(result, input);
 
-        decrypt(&mut result, key, iv);
+        decrypt(&mut result, key, iv).unwrap();
         assert_eq!(result, input);
     }
 }
+
+#[test]
+fn errors() {
+    let key = [0; BLOCK_SIZE];
+    let iv = [0; BLOCK_SIZE];
+
+    let result = encrypt(&mut vec![0; BLOCK_SIZE], [0; 15], iv);
+    assert!(matches!(result, Err(Error::InvalidKeyLength(15))));
+
+    let result = encrypt(&mut vec![0; BLOCK_SIZE], key, [0; 17]);
+    assert!(matches!(result, Err(Error::InvalidIvLength(17))));
+
+    let result = decrypt(&mut vec![0; BLOCK_SIZE + 1], key, iv);
+    assert!(matches!(result, Err(Error::NotBlockMultiple(17))));
+
+    let mut data = vec![0; BLOCK_SIZE];
+    encrypt(&mut data, key, iv).unwrap();
+    data[BLOCK_SIZE - 1] ^= 1; // Corrupts the padding block via the IV chaining
+    let result = decrypt(&mut data, key, iv);
+    assert!(matches!(result, Err(Error::InvalidPadding)));
+}
+
+// Only its block size matters here
+struct SizedCipher(usize);
+
+impl BlockCipher for SizedCipher {
+    fn block_size(&self) -> usize {
+        self.0
+    }
+
+    fn encrypt_block(&self, _: &mut [u8]) {}
+
+    fn decrypt_block(&self, _: &mut [u8]) {}
+}
+
+#[test]
+fn invalid_block_sizes() {
+    for &size in [0, 256].iter() {
+        let mut data = vec![0; 512];
+        let iv = vec![0; size];
+        let result = encrypt_with_cipher(&mut data, SizedCipher(size), &iv);
+        assert!(matches!(result, Err(Error::InvalidBlockSize(x)) if x == size));
+        let result = decrypt_with_cipher(&mut data, SizedCipher(size), &iv);
+        assert!(matches!(result, Err(Error::InvalidBlockSize(x)) if x == size));
+    }
+}
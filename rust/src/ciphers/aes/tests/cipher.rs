@@ -0,0 +1,34 @@
+use super::super::cipher::*;
+use crate::ciphers::BlockCipher;
+use crate::Error;
+
+// Source: FIPS-197 Appendix C
+#[test]
+fn test() {
+    let input = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+    let tests = [
+        (16, "69c4e0d86a7b0430d8cdb78070b4c55a"),
+        (24, "dda97ca4864cdfe06eaf70a0ec0d7191"),
+        (32, "8ea2b7ca516745bfeafc49904b496089"),
+    ];
+
+    for (key_size, expected) in tests.iter() {
+        let key: Vec<u8> = (0..*key_size).collect();
+        let cipher = Aes::new(key).unwrap();
+
+        let mut result = input.clone();
+        cipher.encrypt_block(&mut result);
+        assert_eq!(hex::encode(&result), *expected);
+
+        cipher.decrypt_block(&mut result);
+        assert_eq!(result, input);
+    }
+}
+
+#[test]
+fn invalid_key_length() {
+    for i in [0, 15, 17, 31, 33].iter() {
+        let result = Aes::new(vec![0; *i]);
+        assert!(matches!(result, Err(Error::InvalidKeyLength(x)) if x == *i));
+    }
+}
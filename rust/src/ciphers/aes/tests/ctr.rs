@@ -0,0 +1,72 @@
+use super::super::ctr::*;
+
+use rand::prelude::*;
+
+const BLOCK_SIZE: usize = 16;
+
+#[test]
+fn test() {
+    let mut rng = rand::thread_rng();
+    for layout in &[
+        Layout::Nonce64Counter64LE,
+        Layout::Nonce96Counter32BE,
+        Layout::Nonce32Counter96BE,
+    ] {
+        for i in 8..64 {
+            let input: Vec<u8> = (0..i).map(|_| rng.gen()).collect();
+            let key = rng.gen::<[u8; BLOCK_SIZE]>();
+            let nonce: Vec<u8> = (0..layout.nonce_size()).map(|_| rng.gen()).collect();
+            let mut result = input.clone();
+
+            crypt(&mut result, key, &nonce, *layout).unwrap();
+            assert_eq!(result.len(), input.len());
+            assert_ne!(result, input);
+
+            crypt(&mut result, key, &nonce, *layout).unwrap();
+            assert_eq!(result, input);
+        }
+    }
+}
+
+#[test]
+fn seek() {
+    let mut rng = rand::thread_rng();
+    let key = rng.gen::<[u8; BLOCK_SIZE]>();
+    let nonce = rng.gen::<[u8; 8]>();
+
+    let mut keystream = Keystream::new(key, nonce, Layout::Nonce64Counter64LE).unwrap();
+    let expected: Vec<u8> = keystream.by_ref().take(100).collect();
+    assert_eq!(keystream.position(), 100);
+
+    for i in 0..100 {
+        let mut result = vec![0; 100 - i];
+        keystream.seek(i as u64);
+        keystream.apply(&mut result).unwrap();
+        assert_eq!(result, &expected[i..]);
+    }
+}
+
+#[test]
+fn counter_overflow() {
+    let mut rng = rand::thread_rng();
+    let key = rng.gen::<[u8; BLOCK_SIZE]>();
+    let nonce = rng.gen::<[u8; 12]>();
+    let layout = Layout::Nonce96Counter32BE;
+    let last_block = layout.max_counter() * BLOCK_SIZE as u64;
+
+    let mut keystream = Keystream::new(key, nonce, layout).unwrap();
+    assert!(keystream.block(layout.max_counter() + 1).is_err());
+
+    keystream.seek(last_block);
+    let mut data = [0; BLOCK_SIZE + 1];
+    assert!(keystream.apply(&mut data).is_err());
+    assert_eq!(data, [0; BLOCK_SIZE + 1]);
+    assert_eq!(keystream.position(), last_block);
+
+    keystream.apply(&mut data[..BLOCK_SIZE]).unwrap();
+    assert_eq!(
+        &data[..BLOCK_SIZE],
+        &keystream.block(layout.max_counter()).unwrap()
+    );
+    assert_eq!(keystream.next(), None);
+}
@@ -1,4 +1,6 @@
 use super::super::ecb::*;
+use crate::ciphers::BlockCipher;
+use crate::Error;
 
 use rand::prelude::*;
 
@@ -12,10 +14,72 @@ fn test() {
         let key = rng.gen::<[u8; BLOCK_SIZE]>();
         let mut result = input.clone();
 
-        encrypt(&mut result, key);
+        encrypt(&mut result, key).unwrap();
         assert_ne!(result, input);
 
-        decrypt(&mut result, key);
+        decrypt(&mut result, key).unwrap();
         assert_eq!(result, input);
     }
 }
+
+#[test]
+fn key_sizes() {
+    let mut rng = rand::thread_rng();
+    for key_size in [16, 24, 32].iter() {
+        let input: Vec<u8> = (0..50).map(|_| rng.gen()).collect();
+        let key: Vec<u8> = (0..*key_size).map(|_| rng.gen()).collect();
+        let mut result = input.clone();
+
+        encrypt(&mut result, &key).unwrap();
+        assert_ne!(result, input);
+
+        decrypt(&mut result, &key).unwrap();
+        assert_eq!(result, input);
+    }
+}
+
+// Not a real cipher, just enough to check the mode isnt tied to AES's block size
+struct ToyCipher(u8, usize);
+
+impl BlockCipher for ToyCipher {
+    fn block_size(&self) -> usize {
+        self.1
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        for a in block.iter_mut() {
+            *a = a.wrapping_add(self.0).rotate_left(3);
+        }
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        for a in block.iter_mut() {
+            *a = a.rotate_right(3).wrapping_sub(self.0);
+        }
+    }
+}
+
+#[test]
+fn other_block_cipher() {
+    let input = b"YELLOW SUBMARINE!".to_vec();
+    let cipher = ToyCipher(42, 8);
+    let mut result = input.clone();
+
+    encrypt_with_cipher(&mut result, &cipher).unwrap();
+    assert_eq!(result.len(), 24);
+    assert_ne!(result[..input.len()], input[..]);
+
+    decrypt_with_cipher(&mut result, &cipher).unwrap();
+    assert_eq!(result, input);
+}
+
+#[test]
+fn invalid_block_sizes() {
+    for &size in [0, 256].iter() {
+        let mut data = vec![0; 512];
+        let result = encrypt_with_cipher(&mut data, ToyCipher(42, size));
+        assert!(matches!(result, Err(Error::InvalidBlockSize(x)) if x == size));
+        let result = decrypt_with_cipher(&mut data, ToyCipher(42, size));
+        assert!(matches!(result, Err(Error::InvalidBlockSize(x)) if x == size));
+    }
+}
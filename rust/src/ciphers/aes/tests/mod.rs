@@ -1,2 +1,5 @@
 mod cbc;
+mod cipher;
+mod ctr;
 mod ecb;
+mod soft;
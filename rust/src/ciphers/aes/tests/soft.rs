@@ -0,0 +1,114 @@
+use super::super::cipher::Aes;
+use super::super::ecb;
+use super::super::soft::*;
+use crate::ciphers::BlockCipher;
+
+use rand::prelude::*;
+
+fn state(data: &str) -> State {
+    let mut output = [0; BLOCK_SIZE];
+    output.copy_from_slice(&hex::decode(data).unwrap());
+    output
+}
+
+// Source: FIPS-197 Appendix C
+#[test]
+fn test() {
+    let input = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+    let tests = [
+        (16, "69c4e0d86a7b0430d8cdb78070b4c55a"),
+        (24, "dda97ca4864cdfe06eaf70a0ec0d7191"),
+        (32, "8ea2b7ca516745bfeafc49904b496089"),
+    ];
+
+    for (key_size, expected) in tests.iter() {
+        let key: Vec<u8> = (0..*key_size).collect();
+        let cipher = SoftAes::new(key).unwrap();
+
+        let mut result = input.clone();
+        cipher.encrypt_block(&mut result);
+        assert_eq!(hex::encode(&result), *expected);
+
+        cipher.decrypt_block(&mut result);
+        assert_eq!(result, input);
+    }
+}
+
+// Source: FIPS-197 Appendix A.1 & A.3
+#[test]
+fn key_expansion() {
+    let key = hex::decode("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let round_keys = expand_key(key, 10).unwrap();
+    assert_eq!(round_keys.len(), 11);
+    assert_eq!(round_keys[1], state("a0fafe1788542cb123a339392a6c7605"));
+    assert_eq!(round_keys[10], state("d014f9a8c9ee2589e13f0cc8b6630ca6"));
+
+    let key =
+        hex::decode("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4").unwrap();
+    let round_keys = expand_key(key, 14).unwrap();
+    assert_eq!(round_keys[14], state("fe4890d1e6188d0b046df344706c631e"));
+}
+
+// Source: FIPS-197 Appendix B (round 1)
+#[test]
+fn round_functions() {
+    let mut input = state("193de3bea0f4e22b9ac68d2ae9f84808");
+
+    sub_bytes(&mut input);
+    assert_eq!(input, state("d42711aee0bf98f1b8b45de51e415230"));
+
+    shift_rows(&mut input);
+    assert_eq!(input, state("d4bf5d30e0b452aeb84111f11e2798e5"));
+
+    mix_columns(&mut input);
+    assert_eq!(input, state("046681e5e0cb199a48f8d37a2806264c"));
+
+    add_round_key(&mut input, &state("a0fafe1788542cb123a339392a6c7605"));
+    assert_eq!(input, state("a49c7ff2689f352b6b5bea43026a5049"));
+
+    add_round_key(&mut input, &state("a0fafe1788542cb123a339392a6c7605"));
+    inv_mix_columns(&mut input);
+    assert_eq!(input, state("d4bf5d30e0b452aeb84111f11e2798e5"));
+
+    inv_shift_rows(&mut input);
+    inv_sub_bytes(&mut input);
+    assert_eq!(input, state("193de3bea0f4e22b9ac68d2ae9f84808"));
+}
+
+#[test]
+fn matches_aesni() {
+    let mut rng = rand::thread_rng();
+    for key_size in [16, 24, 32].iter() {
+        let key: Vec<u8> = (0..*key_size).map(|_| rng.gen()).collect();
+        let input: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+
+        let mut expected = input.clone();
+        ecb::encrypt_with_cipher(&mut expected, Aes::new(&key).unwrap()).unwrap();
+
+        let mut result = input.clone();
+        ecb::encrypt_with_cipher(&mut result, SoftAes::new(&key).unwrap()).unwrap();
+        assert_eq!(result, expected);
+
+        ecb::decrypt_with_cipher(&mut result, SoftAes::new(&key).unwrap()).unwrap();
+        assert_eq!(result, input);
+    }
+}
+
+#[test]
+fn reduced_rounds() {
+    let mut rng = rand::thread_rng();
+    let key = rng.gen::<[u8; BLOCK_SIZE]>();
+    for rounds in 0..16 {
+        let cipher = SoftAes::with_rounds(key, rounds).unwrap();
+        assert_eq!(cipher.rounds(), rounds);
+        assert_eq!(cipher.round_keys().len(), rounds + 1);
+
+        let input = rng.gen::<State>();
+        let mut result = input;
+        cipher.encrypt_state(&mut result);
+        assert_ne!(result, input);
+
+        cipher.decrypt_state(&mut result);
+        assert_eq!(result, input);
+    }
+}
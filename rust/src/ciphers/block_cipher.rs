@@ -0,0 +1,36 @@
+use crate::Error;
+use crate::Result;
+
+pub trait BlockCipher {
+    fn block_size(&self) -> usize;
+    fn encrypt_block(&self, block: &mut [u8]);
+    fn decrypt_block(&self, block: &mut [u8]);
+}
+
+impl<C> BlockCipher for &C
+where
+    C: BlockCipher + ?Sized,
+{
+    fn block_size(&self) -> usize {
+        (**self).block_size()
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        (**self).encrypt_block(block)
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        (**self).decrypt_block(block)
+    }
+}
+
+// The block size as the byte PKCS#7 pads with, so from 1 to 255
+pub fn padding_block_size<C>(cipher: &C) -> Result<u8>
+where
+    C: BlockCipher + ?Sized,
+{
+    match cipher.block_size() {
+        x @ 1..=255 => Ok(x as u8),
+        x => Err(Error::InvalidBlockSize(x)),
+    }
+}
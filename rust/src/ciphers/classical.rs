@@ -0,0 +1,171 @@
+use crate::Error;
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Alphabet {
+    symbols: Vec<u8>,
+    positions: Vec<Option<usize>>,
+}
+
+impl Alphabet {
+    pub fn new<S>(symbols: S) -> Result<Alphabet>
+    where
+        S: AsRef<[u8]>,
+    {
+        let symbols = symbols.as_ref();
+        let mut positions = vec![None; 256];
+        for (i, &x) in symbols.iter().enumerate() {
+            if positions[x as usize].replace(i).is_some() {
+                return Err(Error::DuplicateSymbol(x));
+            }
+        }
+
+        Ok(Alphabet {
+            symbols: symbols.to_vec(),
+            positions,
+        })
+    }
+
+    pub fn upper_case() -> Alphabet {
+        Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap()
+    }
+
+    pub fn lower_case() -> Alphabet {
+        Alphabet::new(b"abcdefghijklmnopqrstuvwxyz").unwrap()
+    }
+
+    // Upper and lower case kept apart, like Caesar
+    pub fn letters() -> Vec<Alphabet> {
+        vec![Alphabet::upper_case(), Alphabet::lower_case()]
+    }
+
+    pub fn digits() -> Alphabet {
+        Alphabet::new(b"0123456789").unwrap()
+    }
+
+    pub fn base64() -> Alphabet {
+        Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/").unwrap()
+    }
+
+    // ! to ~, what ROT47 works over
+    pub fn printable() -> Alphabet {
+        Alphabet::new((b'!'..=b'~').collect::<Vec<u8>>()).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn position(&self, symbol: u8) -> Option<usize> {
+        self.positions[symbol as usize]
+    }
+
+    pub fn symbol(&self, position: usize) -> u8 {
+        self.symbols[position % self.len()]
+    }
+}
+
+pub fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Everything is 0 modulo 1, so 0 is its own inverse there
+pub fn mod_inverse(a: usize, modulus: usize) -> Result<usize> {
+    if modulus == 0 {
+        return Err(Error::ZeroModulus);
+    }
+    (0..modulus)
+        .find(|x| (a % modulus) * x % modulus == 1 % modulus)
+        .ok_or(Error::NotInvertible)
+}
+
+// Every symbol x found in one of the alphabets becomes a * x + b, the rest
+// are left alone. `a` has to be coprime with each alphabet's size
+pub fn affine_encrypt<D>(data: D, a: usize, b: usize, alphabets: &[Alphabet]) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    for alphabet in alphabets {
+        if gcd(a, alphabet.len()) != 1 {
+            return Err(Error::NotInvertible);
+        }
+    }
+
+    map(data, alphabets, |x, i| {
+        let size = alphabets[i].len();
+        a % size * x + b % size
+    });
+    Ok(())
+}
+
+pub fn affine_decrypt<D>(data: D, a: usize, b: usize, alphabets: &[Alphabet]) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    let mut inverses = Vec::with_capacity(alphabets.len());
+    for alphabet in alphabets {
+        let size = alphabet.len();
+        inverses.push(mod_inverse(a, size)?);
+    }
+
+    map(data, alphabets, |x, i| {
+        let size = alphabets[i].len();
+        inverses[i] * (x + size - b % size)
+    });
+    Ok(())
+}
+
+pub fn shift<D>(data: D, key: usize, alphabets: &[Alphabet])
+where
+    D: AsMut<[u8]>,
+{
+    map(data, alphabets, |x, i| x + key % alphabets[i].len());
+}
+
+pub fn unshift<D>(data: D, key: usize, alphabets: &[Alphabet])
+where
+    D: AsMut<[u8]>,
+{
+    map(data, alphabets, |x, i| {
+        let size = alphabets[i].len();
+        x + size - key % size
+    });
+}
+
+// Reverses each alphabet, so it decrypts itself
+pub fn atbash<D>(data: D, alphabets: &[Alphabet])
+where
+    D: AsMut<[u8]>,
+{
+    map(data, alphabets, |x, i| alphabets[i].len() - 1 - x);
+}
+
+pub fn rot47<D>(data: D)
+where
+    D: AsMut<[u8]>,
+{
+    shift(data, 47, &[Alphabet::printable()]);
+}
+
+fn map<D, F>(mut data: D, alphabets: &[Alphabet], function: F)
+where
+    D: AsMut<[u8]>,
+    F: Fn(usize, usize) -> usize,
+{
+    for a in data.as_mut().iter_mut() {
+        for (i, alphabet) in alphabets.iter().enumerate() {
+            if let Some(x) = alphabet.position(*a) {
+                *a = alphabet.symbol(function(x, i));
+                break;
+            }
+        }
+    }
+}
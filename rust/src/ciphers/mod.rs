@@ -1,3 +1,11 @@
+mod block_cipher;
+pub use block_cipher::*;
+
 pub mod aes;
 pub mod caesar;
+pub mod classical;
+pub mod mt19937;
+pub mod substitution;
+pub mod transposition;
+pub mod vigenere;
 pub mod xor;
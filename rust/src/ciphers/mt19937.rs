@@ -0,0 +1,23 @@
+use crate::prng::mt19937::Mt19937;
+use crate::utils::xor;
+
+// Keystream is each output as little endian bytes (Cryptopals 24)
+pub fn keystream(seed: u32, size: usize) -> Vec<u8> {
+    let mut rng = Mt19937::new(seed);
+    let mut output = Vec::with_capacity(size + 4);
+    while output.len() < size {
+        output.extend(&rng.extract_number().to_le_bytes());
+    }
+    output.truncate(size);
+    output
+}
+
+pub fn crypt<D>(mut data: D, seed: u32)
+where
+    D: AsMut<[u8]>,
+{
+    let data = data.as_mut();
+    let keystream = keystream(seed, data.len());
+
+    xor(data, keystream);
+}
@@ -0,0 +1,77 @@
+use super::caesar::ALPHABET_SIZE;
+use crate::Error;
+use crate::Result;
+
+pub type Key = [u8; ALPHABET_SIZE as usize];
+
+pub const IDENTITY_KEY: Key = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// The key lists what A to Z encrypt to, case is kept and non-letters skipped
+pub fn encrypt<D, K>(data: D, key: K) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+{
+    crypt(data, &parse_key(key)?);
+    Ok(())
+}
+
+pub fn decrypt<D, K>(data: D, key: K) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+{
+    crypt(data, &invert_key(key)?);
+    Ok(())
+}
+
+pub fn invert_key<K>(key: K) -> Result<Key>
+where
+    K: AsRef<[u8]>,
+{
+    let mut output = IDENTITY_KEY;
+    for (i, x) in parse_key(key)?.iter().enumerate() {
+        output[(x - b'A') as usize] = b'A' + i as u8;
+    }
+    Ok(output)
+}
+
+// Every letter once, in either case
+fn parse_key<K>(key: K) -> Result<Key>
+where
+    K: AsRef<[u8]>,
+{
+    let key = key.as_ref();
+    if key.len() != ALPHABET_SIZE as usize {
+        return Err(Error::InvalidKeyLength(key.len()));
+    }
+
+    let mut output = IDENTITY_KEY;
+    let mut seen = [false; ALPHABET_SIZE as usize];
+    for (i, &x) in key.iter().enumerate() {
+        if !x.is_ascii_alphabetic() {
+            return Err(Error::InvalidSymbol(x));
+        }
+        output[i] = x.to_ascii_uppercase();
+
+        let letter = (output[i] - b'A') as usize;
+        if seen[letter] {
+            return Err(Error::DuplicateSymbol(x));
+        }
+        seen[letter] = true;
+    }
+    Ok(output)
+}
+
+fn crypt<D>(mut data: D, key: &Key)
+where
+    D: AsMut<[u8]>,
+{
+    for a in data.as_mut().iter_mut() {
+        *a = match a {
+            b'A'..=b'Z' => key[(*a - b'A') as usize],
+            b'a'..=b'z' => key[(*a - b'a') as usize].to_ascii_lowercase(),
+            _ => continue,
+        };
+    }
+}
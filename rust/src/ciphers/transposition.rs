@@ -0,0 +1,117 @@
+use crate::Error;
+use crate::Result;
+
+// Columns are read in the alphabetical order of their keyword letter, ties
+// left to right
+pub fn column_order<K>(keyword: K) -> Vec<usize>
+where
+    K: AsRef<[u8]>,
+{
+    let keyword = keyword.as_ref();
+    let mut output: Vec<usize> = (0..keyword.len()).collect();
+    output.sort_by_key(|&i| keyword[i].to_ascii_uppercase());
+    output
+}
+
+pub fn columnar_encrypt<D, K>(data: D, keyword: K) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+{
+    columnar_encrypt_with_order(data, &column_order(keyword))
+}
+
+pub fn columnar_decrypt<D, K>(data: D, keyword: K) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+{
+    columnar_decrypt_with_order(data, &column_order(keyword))
+}
+
+// Written out in rows as wide as the order, then read down the columns in
+// that order. The last row is left short rather than padded
+pub fn columnar_encrypt_with_order<D>(mut data: D, order: &[usize]) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    let data = data.as_mut();
+    encrypt(data, &columnar_permutation(data.len(), order)?);
+    Ok(())
+}
+
+pub fn columnar_decrypt_with_order<D>(mut data: D, order: &[usize]) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    let data = data.as_mut();
+    decrypt(data, &columnar_permutation(data.len(), order)?);
+    Ok(())
+}
+
+// Written out zigzagging down and up the rails, then read rail by rail
+pub fn rail_fence_encrypt<D>(mut data: D, rails: usize) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    let data = data.as_mut();
+    encrypt(data, &rail_fence_permutation(data.len(), rails)?);
+    Ok(())
+}
+
+pub fn rail_fence_decrypt<D>(mut data: D, rails: usize) -> Result<()>
+where
+    D: AsMut<[u8]>,
+{
+    let data = data.as_mut();
+    decrypt(data, &rail_fence_permutation(data.len(), rails)?);
+    Ok(())
+}
+
+// Where each byte of the cipher text comes from in the plain text
+fn columnar_permutation(size: usize, order: &[usize]) -> Result<Vec<usize>> {
+    if order.is_empty() {
+        return Err(Error::InvalidKeyLength(0));
+    }
+    let mut sorted = order.to_vec();
+    sorted.sort_unstable();
+    if sorted.iter().enumerate().any(|(i, &x)| i != x) {
+        return Err(Error::InvalidKey("Column order isn't a permutation"));
+    }
+
+    let columns = order.len();
+    Ok(order
+        .iter()
+        .flat_map(|&column| (column..size).step_by(columns))
+        .collect())
+}
+
+fn rail_fence_permutation(size: usize, rails: usize) -> Result<Vec<usize>> {
+    if rails == 0 {
+        return Err(Error::InvalidKey("No rails"));
+    }
+
+    let cycle = (2 * rails - 2).max(1);
+    let rail = |i: usize| {
+        let x = i % cycle;
+        x.min(cycle - x)
+    };
+
+    let mut output: Vec<usize> = (0..size).collect();
+    output.sort_by_key(|&i| rail(i));
+    Ok(output)
+}
+
+fn encrypt(data: &mut [u8], permutation: &[usize]) {
+    let plain_text = data.to_vec();
+    for (a, &i) in data.iter_mut().zip(permutation) {
+        *a = plain_text[i];
+    }
+}
+
+fn decrypt(data: &mut [u8], permutation: &[usize]) {
+    let cipher_text = data.to_vec();
+    for (&a, &i) in cipher_text.iter().zip(permutation) {
+        data[i] = a;
+    }
+}
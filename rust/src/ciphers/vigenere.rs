@@ -0,0 +1,31 @@
+use std::slice;
+
+use super::caesar::{self, Shift, ALPHABET_SIZE};
+use crate::Error;
+use crate::Result;
+
+// Key letters shift like Caesar with A being 0, only letters in the data use
+// up a key letter
+pub fn crypt<D, K>(mut data: D, key: K, shift: Shift) -> Result<()>
+where
+    D: AsMut<[u8]>,
+    K: AsRef<[u8]>,
+{
+    let data = data.as_mut();
+    let key = key.as_ref();
+    if key.is_empty() {
+        return Err(Error::InvalidKeyLength(0));
+    }
+    if let Some(&x) = key.iter().find(|x| !x.is_ascii_alphabetic()) {
+        return Err(Error::InvalidSymbol(x));
+    }
+
+    let mut shifts = key
+        .iter()
+        .map(|x| (x.to_ascii_uppercase() - b'A') % ALPHABET_SIZE)
+        .cycle();
+    for a in data.iter_mut().filter(|x| x.is_ascii_alphabetic()) {
+        caesar::crypt(slice::from_mut(a), shifts.next().unwrap(), shift);
+    }
+    Ok(())
+}
@@ -1 +1,2 @@
 pub mod oracle_prefixing;
+pub mod padding_oracle;
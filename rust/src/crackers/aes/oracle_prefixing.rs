@@ -1,25 +1,48 @@
 use crate::oracles::aes::ecb::prefixing::Oracle;
+use crate::Error;
+use crate::Result;
 
 const PADDING_BYTE: u8 = b' ';
+const MARKER_START_BYTE: u8 = b'S';
+const MARKER_END_BYTE: u8 = b'E';
+
+// Wraps the oracle so our input always starts on a fresh block,
+// hiding any unknown prefix the oracle adds in front of it
+struct AlignedOracle<'a> {
+    oracle: &'a Oracle,
+    padding: Vec<u8>,
+    skip: usize,
+}
+
+impl<'a> AlignedOracle<'a> {
+    fn function<P>(&self, input: P) -> Vec<u8>
+    where
+        P: AsRef<[u8]>,
+    {
+        let mut data = self.padding.clone();
+        data.extend(input.as_ref());
+        self.oracle.function(data)[self.skip..].to_vec()
+    }
+}
+
+pub fn crack(oracle: &Oracle) -> Result<Vec<u8>> {
+    let block_size = detect_block_size(oracle);
+    let oracle = align_oracle(oracle, block_size)?;
 
-pub fn crack(oracle: &Oracle) -> Result<Vec<u8>, &'static str> {
-    let (block_size, data_size) = detect_block_size_and_data_size(&oracle);
+    let data_size = detect_data_size(&oracle);
     let mut output = Vec::with_capacity(data_size);
 
     while output.len() < data_size {
         let prefix = get_next_block_prefix(&output, block_size);
         let target = get_next_target_block(&oracle, &output, block_size);
 
-        match find_target_blocks_last_byte(&oracle, target, prefix) {
-            Ok(i) => output.push(i),
-            Err(e) => return Err(e),
-        }
+        output.push(find_target_blocks_last_byte(&oracle, target, prefix)?);
     }
 
     Ok(output)
 }
 
-fn detect_block_size_and_data_size(oracle: &Oracle) -> (usize, usize) {
+fn detect_block_size(oracle: &Oracle) -> usize {
     let init_len = oracle.function([]).len();
     let mut new_len = init_len;
 
@@ -29,12 +52,54 @@ fn detect_block_size_and_data_size(oracle: &Oracle) -> (usize, usize) {
         new_len = oracle.function(&buffer).len();
     }
 
-    let block_size = new_len - init_len;
-    let data_size = init_len - buffer.len();
-    (block_size, data_size)
+    new_len - init_len
+}
+
+fn detect_data_size(oracle: &AlignedOracle) -> usize {
+    let init_len = oracle.function([]).len();
+    let mut new_len = init_len;
+
+    let mut buffer = vec![];
+    while new_len == init_len {
+        buffer.push(PADDING_BYTE);
+        new_len = oracle.function(&buffer).len();
+    }
+
+    init_len - buffer.len()
+}
+
+fn get_first_duplicate_block_index(data: &[u8], block_size: usize) -> Option<usize> {
+    let blocks: Vec<&[u8]> = data.chunks_exact(block_size).collect();
+    blocks.windows(2).position(|x| x[0] == x[1])
+}
+
+// Send two identical marker blocks behind a growing amount of padding,
+// once they encrypt to the same cipher text they are block aligned
+fn align_oracle(oracle: &Oracle, block_size: usize) -> Result<AlignedOracle<'_>> {
+    let mut marker = vec![MARKER_START_BYTE; block_size];
+    marker[block_size - 1] = MARKER_END_BYTE;
+
+    let mut padding = vec![];
+    for _ in 0..block_size {
+        let mut data = padding.clone();
+        data.extend(&marker);
+        data.extend(&marker);
+
+        if let Some(i) = get_first_duplicate_block_index(&oracle.function(data), block_size) {
+            return Ok(AlignedOracle {
+                oracle,
+                padding,
+                skip: i * block_size,
+            });
+        }
+        padding.push(PADDING_BYTE);
+    }
+    Err(Error::SearchExhausted(
+        "Unable to detect ECB mode from oracle",
+    ))
 }
 
-fn get_next_target_block(oracle: &Oracle, found: &[u8], block_size: usize) -> Vec<u8> {
+fn get_next_target_block(oracle: &AlignedOracle, found: &[u8], block_size: usize) -> Vec<u8> {
     let byte_index = found.len() % block_size;
     let padding = vec![PADDING_BYTE; block_size - byte_index - 1];
 
@@ -58,10 +123,10 @@ fn get_next_block_prefix(found: &[u8], block_size: usize) -> Vec<u8> {
 }
 
 fn find_target_blocks_last_byte(
-    oracle: &Oracle,
+    oracle: &AlignedOracle,
     target: Vec<u8>,
     prefix: Vec<u8>,
-) -> Result<u8, &'static str> {
+) -> Result<u8> {
     let mut block = prefix;
     block.push(PADDING_BYTE);
 
@@ -74,5 +139,5 @@ fn find_target_blocks_last_byte(
             return Ok(i);
         }
     }
-    Err("Unable to find target block")
+    Err(Error::SearchExhausted("Unable to find target block"))
 }
@@ -0,0 +1,90 @@
+use crate::utils::unpad;
+use crate::utils::xor;
+use crate::Error;
+use crate::Result;
+
+const BLOCK_SIZE: usize = 16;
+
+pub trait PaddingOracle {
+    fn valid_padding(&self, iv: &[u8], cipher_text: &[u8]) -> bool;
+}
+
+impl<F> PaddingOracle for F
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    fn valid_padding(&self, iv: &[u8], cipher_text: &[u8]) -> bool {
+        self(iv, cipher_text)
+    }
+}
+
+pub fn crack<O, I, C>(oracle: &O, iv: I, cipher_text: C) -> Result<Vec<u8>>
+where
+    O: PaddingOracle,
+    I: AsRef<[u8]>,
+    C: AsRef<[u8]>,
+{
+    let iv = iv.as_ref();
+    let cipher_text = cipher_text.as_ref();
+    if iv.len() != BLOCK_SIZE {
+        return Err(Error::InvalidIvLength(iv.len()));
+    }
+    if !cipher_text.len().is_multiple_of(BLOCK_SIZE) {
+        return Err(Error::NotBlockMultiple(cipher_text.len()));
+    }
+
+    let mut output = Vec::with_capacity(cipher_text.len());
+    let mut last_block = iv;
+    for block in cipher_text.chunks_exact(BLOCK_SIZE) {
+        let mut plain_text = find_intermediate_block(oracle, block)?;
+        xor(&mut plain_text, last_block);
+        output.extend(plain_text);
+        last_block = block;
+    }
+
+    unpad(&mut output, BLOCK_SIZE as u8)?;
+    Ok(output)
+}
+
+fn find_intermediate_block<O>(oracle: &O, block: &[u8]) -> Result<Vec<u8>>
+where
+    O: PaddingOracle,
+{
+    let mut output = vec![0; BLOCK_SIZE];
+    let mut iv = vec![0; BLOCK_SIZE];
+
+    for padding in 1..=BLOCK_SIZE {
+        let index = BLOCK_SIZE - padding;
+        for i in index + 1..BLOCK_SIZE {
+            iv[i] = output[i] ^ padding as u8;
+        }
+
+        output[index] = find_padding_byte(oracle, &mut iv, block, index)? ^ padding as u8;
+    }
+    Ok(output)
+}
+
+fn find_padding_byte<O>(oracle: &O, iv: &mut [u8], block: &[u8], index: usize) -> Result<u8>
+where
+    O: PaddingOracle,
+{
+    for i in 0..=255 {
+        iv[index] = i;
+        if !oracle.valid_padding(iv, block) {
+            continue;
+        }
+
+        // The last byte may have landed on a longer valid padding (eg: \x02\x02)
+        // Changing the byte before it rules that out
+        if index == BLOCK_SIZE - 1 {
+            iv[index - 1] ^= 0xff;
+            let valid = oracle.valid_padding(iv, block);
+            iv[index - 1] ^= 0xff;
+            if !valid {
+                continue;
+            }
+        }
+        return Ok(i);
+    }
+    Err(Error::SearchExhausted("Unable to find valid padding"))
+}
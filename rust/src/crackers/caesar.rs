@@ -1,9 +1,9 @@
 use crate::ciphers::caesar::*;
-use crate::text_scoring::common::count as get_score;
+use crate::text_scoring::{Frequency, Scorer};
 
 #[derive(Debug)]
 pub struct CrackResult {
-    pub score: usize,
+    pub score: f64,
     pub key: u8,
     pub shift: Shift,
     pub plain_text: Vec<u8>,
@@ -12,10 +12,18 @@ pub struct CrackResult {
 pub fn crack<C>(cipher_text: C) -> CrackResult
 where
     C: AsRef<[u8]>,
+{
+    crack_with(&Frequency, cipher_text)
+}
+
+pub fn crack_with<S, C>(scorer: &S, cipher_text: C) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
 {
     let cipher_text = cipher_text.as_ref();
     let mut output = CrackResult {
-        score: 0,
+        score: f64::NEG_INFINITY,
         key: 0,
         shift: Shift::Right,
         plain_text: vec![],
@@ -25,7 +33,7 @@ where
         let mut plain_text = cipher_text.to_vec();
         crypt(&mut plain_text, i, Shift::Right);
 
-        let score = get_score(&plain_text);
+        let score = scorer.score(&plain_text);
         if score > output.score {
             output.score = score;
             output.plain_text = plain_text;
@@ -0,0 +1,91 @@
+use crate::ciphers::classical::{affine_decrypt, gcd, unshift, Alphabet};
+use crate::text_scoring::{Frequency, Scorer};
+
+#[derive(Debug)]
+pub struct CrackResult {
+    pub score: f64,
+    pub a: usize, // Always 1 for shifts
+    pub b: usize,
+    pub plain_text: Vec<u8>,
+}
+
+pub fn crack_shift<C>(cipher_text: C, alphabets: &[Alphabet]) -> CrackResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_shift_with(&Frequency, cipher_text, alphabets)
+}
+
+// Tries every shift up to the biggest alphabet's size
+pub fn crack_shift_with<S, C>(scorer: &S, cipher_text: C, alphabets: &[Alphabet]) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output = CrackResult {
+        score: f64::NEG_INFINITY,
+        a: 1,
+        b: 0,
+        plain_text: vec![],
+    };
+
+    for key in 0..max_size(alphabets) {
+        let mut plain_text = cipher_text.to_vec();
+        unshift(&mut plain_text, key, alphabets);
+
+        let score = scorer.score(&plain_text);
+        if score > output.score {
+            output.score = score;
+            output.b = key;
+            output.plain_text = plain_text;
+        }
+    }
+    output
+}
+
+pub fn crack_affine<C>(cipher_text: C, alphabets: &[Alphabet]) -> CrackResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_affine_with(&Frequency, cipher_text, alphabets)
+}
+
+// Tries every `a` coprime with all the alphabets' sizes with every `b`
+pub fn crack_affine_with<S, C>(scorer: &S, cipher_text: C, alphabets: &[Alphabet]) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output = CrackResult {
+        score: f64::NEG_INFINITY,
+        a: 1,
+        b: 0,
+        plain_text: vec![],
+    };
+
+    let size = max_size(alphabets);
+    let keys = (1..size).filter(|&a| alphabets.iter().all(|x| gcd(a, x.len()) == 1));
+    for a in keys {
+        for b in 0..size {
+            let mut plain_text = cipher_text.to_vec();
+            if affine_decrypt(&mut plain_text, a, b, alphabets).is_err() {
+                continue;
+            }
+
+            let score = scorer.score(&plain_text);
+            if score > output.score {
+                output.score = score;
+                output.a = a;
+                output.b = b;
+                output.plain_text = plain_text;
+            }
+        }
+    }
+    output
+}
+
+fn max_size(alphabets: &[Alphabet]) -> usize {
+    alphabets.iter().map(|x| x.len()).max().unwrap_or(0)
+}
@@ -0,0 +1,117 @@
+use crate::crackers::xor::single_byte::crack_with as single_byte_cracker;
+use crate::text_scoring::{Frequency, Scorer};
+use crate::Error;
+use crate::Result;
+
+pub struct CrackResult {
+    pub keystream: Vec<u8>,
+    pub cipher_texts: Vec<Vec<u8>>,
+    pub plain_texts: Vec<Vec<u8>>,
+}
+
+impl CrackResult {
+    fn new(keystream: Vec<u8>, cipher_texts: Vec<Vec<u8>>) -> CrackResult {
+        let mut output = CrackResult {
+            keystream,
+            cipher_texts,
+            plain_texts: vec![],
+        };
+        output.decrypt();
+        output
+    }
+
+    fn decrypt(&mut self) {
+        self.plain_texts = self
+            .cipher_texts
+            .iter()
+            .map(|x| x.iter().zip(&self.keystream).map(|(a, b)| a ^ b).collect())
+            .collect();
+    }
+
+    // For refining by hand, eg: "row 3 should read 'e' at index 20". Errors
+    // when the row is too short to have that index
+    pub fn set_plain_text_byte(&mut self, row: usize, index: usize, value: u8) -> Result<()> {
+        let cipher_text = self
+            .cipher_texts
+            .get(row)
+            .ok_or(Error::NotEnoughData(row))?;
+        let byte = cipher_text.get(index).ok_or(Error::NotEnoughData(index))?;
+        let key = self
+            .keystream
+            .get_mut(index)
+            .ok_or(Error::NotEnoughData(index))?;
+
+        *key = byte ^ value;
+        self.decrypt();
+        Ok(())
+    }
+}
+
+// Cracks every column, the later columns only have the longer cipher texts
+// in them so expect them to need refining by hand
+pub fn crack<L, E>(cipher_texts: L) -> CrackResult
+where
+    L: AsRef<[E]>,
+    E: AsRef<[u8]>,
+{
+    crack_with(&Frequency, cipher_texts)
+}
+
+pub fn crack_with<S, L, E>(scorer: &S, cipher_texts: L) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    L: AsRef<[E]>,
+    E: AsRef<[u8]>,
+{
+    let cipher_texts: Vec<Vec<u8>> = cipher_texts
+        .as_ref()
+        .iter()
+        .map(|x| x.as_ref().to_vec())
+        .collect();
+
+    let keystream = transpose(&cipher_texts)
+        .iter()
+        .map(|x| single_byte_cracker(scorer, x).key)
+        .collect();
+    CrackResult::new(keystream, cipher_texts)
+}
+
+// Truncates every cipher text to the shortest one first (Cryptopals 20)
+pub fn crack_truncated<L, E>(cipher_texts: L) -> CrackResult
+where
+    L: AsRef<[E]>,
+    E: AsRef<[u8]>,
+{
+    crack_truncated_with(&Frequency, cipher_texts)
+}
+
+pub fn crack_truncated_with<S, L, E>(scorer: &S, cipher_texts: L) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    L: AsRef<[E]>,
+    E: AsRef<[u8]>,
+{
+    let cipher_texts = cipher_texts.as_ref();
+    let size = cipher_texts
+        .iter()
+        .map(|x| x.as_ref().len())
+        .min()
+        .unwrap_or(0);
+
+    let cipher_texts: Vec<&[u8]> = cipher_texts.iter().map(|x| &x.as_ref()[..size]).collect();
+    crack_with(scorer, cipher_texts)
+}
+
+fn transpose(cipher_texts: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut output: Vec<Vec<u8>> = vec![];
+
+    for cipher_text in cipher_texts {
+        for (i, &x) in cipher_text.iter().enumerate() {
+            if i >= output.len() {
+                output.push(vec![]);
+            }
+            output[i].push(x);
+        }
+    }
+    output
+}
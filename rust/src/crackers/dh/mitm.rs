@@ -0,0 +1,153 @@
+use num_traits::{One, Zero};
+
+use crate::math::bigint::BigUint;
+use crate::protocols::dh::echo::{Middleman, Packet};
+use crate::protocols::dh::exchange::{decrypt_message, encrypt_message, session_key, KEY_SIZE};
+use crate::Error;
+use crate::Result;
+
+// Swaps both public keys for p, so both secrets are p^x mod p = 0 (Cryptopals 34)
+#[derive(Default)]
+pub struct KeyFixing {
+    p: Option<BigUint>,
+    pub plain_texts: Vec<Vec<u8>>, // Everything read, both ways
+}
+
+impl KeyFixing {
+    pub fn new() -> KeyFixing {
+        KeyFixing::default()
+    }
+
+    fn swap_public_key(&self) -> Result<Packet> {
+        match &self.p {
+            Some(p) => Ok(Packet::PublicKey(p.clone())),
+            None => Err(Error::Protocol("Public key sent before the parameters")),
+        }
+    }
+
+    fn read(&mut self, data: &[u8]) -> Result<()> {
+        let key = session_key(&BigUint::zero());
+        self.plain_texts.push(decrypt_message(key, data)?);
+        Ok(())
+    }
+}
+
+impl Middleman for KeyFixing {
+    fn alice_to_bob(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::Parameters(x) => {
+                self.p = Some(x.p.clone());
+                Ok(Packet::Parameters(x))
+            }
+            Packet::PublicKey(_) => self.swap_public_key(),
+            Packet::Message(x) => {
+                self.read(&x)?;
+                Ok(Packet::Message(x))
+            }
+            x => Ok(x),
+        }
+    }
+
+    fn bob_to_alice(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::PublicKey(_) => self.swap_public_key(),
+            Packet::Message(x) => {
+                self.read(&x)?;
+                Ok(Packet::Message(x))
+            }
+            x => Ok(x),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Generator {
+    One,
+    P,
+    PMinusOne,
+}
+
+// Hands Bob a bad g and that same g as Alice's public key, so Bob's secret is
+// g^b, the public key he sends back. That's 1, 0 or for p - 1 either 1 or
+// p - 1, which is swapped for 1 so Alice's secret is exactly what she gets
+// sent whatever a is (Cryptopals 35)
+pub struct MaliciousGenerator {
+    generator: Generator,
+    parameters: Option<(BigUint, BigUint)>, // p and the bad g
+    alice_key: Option<[u8; KEY_SIZE]>,
+    bob_key: Option<[u8; KEY_SIZE]>,
+    pub plain_texts: Vec<Vec<u8>>, // Everything read, both ways
+}
+
+impl MaliciousGenerator {
+    pub fn new(generator: Generator) -> MaliciousGenerator {
+        MaliciousGenerator {
+            generator,
+            parameters: None,
+            alice_key: None,
+            bob_key: None,
+            plain_texts: vec![],
+        }
+    }
+
+    fn relay_alice_message(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        let bob_key = self.bob_key.ok_or(Error::Protocol("No session key"))?;
+        let alice_key = self.alice_key.ok_or(Error::Protocol("No session key"))?;
+
+        let plain_text = decrypt_message(alice_key, data)?;
+        let output = encrypt_message(bob_key, &plain_text)?;
+        self.plain_texts.push(plain_text);
+        Ok(output)
+    }
+
+    fn relay_bob_message(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        let bob_key = self.bob_key.ok_or(Error::Protocol("No session key"))?;
+        let alice_key = self.alice_key.ok_or(Error::Protocol("No session key"))?;
+
+        let plain_text = decrypt_message(bob_key, data)?;
+        let output = encrypt_message(alice_key, &plain_text)?;
+        self.plain_texts.push(plain_text);
+        Ok(output)
+    }
+}
+
+impl Middleman for MaliciousGenerator {
+    fn alice_to_bob(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::Parameters(mut x) => {
+                x.g = match self.generator {
+                    Generator::One => BigUint::one(),
+                    Generator::P => x.p.clone(),
+                    Generator::PMinusOne => &x.p - 1u32,
+                };
+                self.parameters = Some((x.p.clone(), x.g.clone()));
+                Ok(Packet::Parameters(x))
+            }
+            Packet::PublicKey(_) => match &self.parameters {
+                Some((_, g)) => Ok(Packet::PublicKey(g.clone())),
+                None => Err(Error::Protocol("Public key sent before the parameters")),
+            },
+            Packet::Message(x) => Ok(Packet::Message(self.relay_alice_message(&x)?)),
+            x => Ok(x),
+        }
+    }
+
+    fn bob_to_alice(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::PublicKey(x) => {
+                let p = match &self.parameters {
+                    Some((p, _)) => p,
+                    None => return Err(Error::Protocol("Public key sent before the parameters")),
+                };
+
+                // (p - 1)^a depends on a, but 1^a and 0^a don't
+                self.bob_key = Some(session_key(&x));
+                let x = if x == p - 1u32 { BigUint::one() } else { x };
+                self.alice_key = Some(session_key(&x));
+                Ok(Packet::PublicKey(x))
+            }
+            Packet::Message(x) => Ok(Packet::Message(self.relay_bob_message(&x)?)),
+            x => Ok(x),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use crate::hashes::sha1::Sha1;
+use crate::hashes::ResumableHash;
+use crate::Error;
+use crate::Result;
+
+pub trait MacOracle {
+    fn verify(&self, message: &[u8], mac: &[u8]) -> bool;
+}
+
+impl<F> MacOracle for F
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    fn verify(&self, message: &[u8], mac: &[u8]) -> bool {
+        self(message, mac)
+    }
+}
+
+pub struct Forgery {
+    pub key_size: usize,
+    pub message: Vec<u8>, // The original message, its glue padding then the suffix
+    pub mac: Vec<u8>,
+}
+
+pub fn forge<M, A, S>(message: M, mac: A, suffix: S, key_size: usize) -> Result<Forgery>
+where
+    M: AsRef<[u8]>,
+    A: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    forge_with::<Sha1, _, _, _>(message, mac, suffix, key_size)
+}
+
+// Forges H(key || message || padding || suffix) from H(key || message)
+// assuming the key is `key_size` bytes long
+pub fn forge_with<H, M, A, S>(message: M, mac: A, suffix: S, key_size: usize) -> Result<Forgery>
+where
+    H: ResumableHash,
+    M: AsRef<[u8]>,
+    A: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    let (message, suffix) = (message.as_ref(), suffix.as_ref());
+    let original_size = (key_size + message.len()) as u64;
+    let glue = H::padding(original_size);
+
+    let mut hash = H::from_digest(mac.as_ref(), original_size + glue.len() as u64)?;
+    hash.update(suffix);
+
+    let mut forged = message.to_vec();
+    forged.extend(glue);
+    forged.extend(suffix);
+    Ok(Forgery {
+        key_size,
+        message: forged,
+        mac: hash.digest(),
+    })
+}
+
+pub fn crack<O, M, A, S>(
+    oracle: &O,
+    message: M,
+    mac: A,
+    suffix: S,
+    key_sizes: RangeInclusive<usize>,
+) -> Result<Forgery>
+where
+    O: MacOracle + ?Sized,
+    M: AsRef<[u8]>,
+    A: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    crack_with::<Sha1, _, _, _, _>(oracle, message, mac, suffix, key_sizes)
+}
+
+// Tries every key size in the range until the oracle accepts a forgery
+pub fn crack_with<H, O, M, A, S>(
+    oracle: &O,
+    message: M,
+    mac: A,
+    suffix: S,
+    key_sizes: RangeInclusive<usize>,
+) -> Result<Forgery>
+where
+    H: ResumableHash,
+    O: MacOracle + ?Sized,
+    M: AsRef<[u8]>,
+    A: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    let (message, mac, suffix) = (message.as_ref(), mac.as_ref(), suffix.as_ref());
+
+    for key_size in key_sizes {
+        let forgery = forge_with::<H, _, _, _>(message, mac, suffix, key_size)?;
+        if oracle.verify(&forgery.message, &forgery.mac) {
+            return Ok(forgery);
+        }
+    }
+    Err(Error::SearchExhausted("Unable to find key size"))
+}
@@ -0,0 +1 @@
+pub mod length_extension;
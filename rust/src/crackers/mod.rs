@@ -1,3 +1,14 @@
 pub mod aes;
 pub mod caesar;
+pub mod classical;
+pub mod ctr;
+pub mod dh;
+pub mod hash;
+pub mod prng;
+pub mod rsa;
+pub mod srp;
+pub mod substitution;
+pub mod timing;
+pub mod transposition;
+pub mod vigenere;
 pub mod xor;
@@ -0,0 +1,2 @@
+pub mod mt19937;
+pub mod seed;
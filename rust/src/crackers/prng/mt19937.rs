@@ -0,0 +1,47 @@
+use crate::prng::mt19937::{untemper, untemper_64, Mt19937, Mt19937_64};
+use crate::Error;
+use crate::Result;
+
+// Rebuilds the generator from its first STATE_SIZE outputs, any outputs given
+// after that are skipped so the clone carries on from where the target is
+pub fn clone_from_outputs<O>(outputs: O) -> Result<Mt19937>
+where
+    O: AsRef<[u32]>,
+{
+    let outputs = outputs.as_ref();
+    if outputs.len() < Mt19937::STATE_SIZE {
+        return Err(Error::NotEnoughData(outputs.len()));
+    }
+
+    let mut state = [0; Mt19937::STATE_SIZE];
+    for (a, b) in state.iter_mut().zip(outputs) {
+        *a = untemper(*b);
+    }
+
+    let mut output = Mt19937::from_state(state);
+    for _ in Mt19937::STATE_SIZE..outputs.len() {
+        output.extract_number();
+    }
+    Ok(output)
+}
+
+pub fn clone_from_outputs_64<O>(outputs: O) -> Result<Mt19937_64>
+where
+    O: AsRef<[u64]>,
+{
+    let outputs = outputs.as_ref();
+    if outputs.len() < Mt19937_64::STATE_SIZE {
+        return Err(Error::NotEnoughData(outputs.len()));
+    }
+
+    let mut state = [0; Mt19937_64::STATE_SIZE];
+    for (a, b) in state.iter_mut().zip(outputs) {
+        *a = untemper_64(*b);
+    }
+
+    let mut output = Mt19937_64::from_state(state);
+    for _ in Mt19937_64::STATE_SIZE..outputs.len() {
+        output.extract_number();
+    }
+    Ok(output)
+}
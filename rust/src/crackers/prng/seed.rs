@@ -0,0 +1,90 @@
+use std::ops::RangeInclusive;
+
+use crate::ciphers::mt19937::keystream;
+use crate::prng::mt19937::Mt19937;
+use crate::utils::unix_timestamp;
+use crate::Error;
+use crate::Result;
+
+pub const SEEDS_16BIT: RangeInclusive<u32> = 0..=0xffff;
+
+// Finds the seed whose first outputs match the ones given
+pub fn crack<O>(outputs: O, seeds: RangeInclusive<u32>) -> Result<u32>
+where
+    O: AsRef<[u32]>,
+{
+    let outputs = outputs.as_ref();
+    if outputs.is_empty() {
+        return Err(Error::NotEnoughData(0));
+    }
+
+    for seed in seeds {
+        let mut rng = Mt19937::new(seed);
+        if outputs.iter().all(|&x| x == rng.extract_number()) {
+            return Ok(seed);
+        }
+    }
+    Err(Error::SearchExhausted("Unable to find seed"))
+}
+
+// Searches the unix timestamps from `seconds` ago up to now
+pub fn crack_recent_timestamp<O>(outputs: O, seconds: u32) -> Result<u32>
+where
+    O: AsRef<[u32]>,
+{
+    let now = unix_timestamp();
+    crack(outputs, now.saturating_sub(seconds)..=now)
+}
+
+// Recovers the key of ciphers::mt19937 when the plain text ends with a known suffix
+pub fn crack_stream_cipher<C, S>(cipher_text: C, suffix: S) -> Result<u16>
+where
+    C: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let suffix = suffix.as_ref();
+    if suffix.is_empty() || suffix.len() > cipher_text.len() {
+        return Err(Error::NotEnoughData(suffix.len()));
+    }
+
+    let offset = cipher_text.len() - suffix.len();
+    let target: Vec<u8> = cipher_text[offset..]
+        .iter()
+        .zip(suffix)
+        .map(|(a, b)| a ^ b)
+        .collect();
+
+    for seed in SEEDS_16BIT {
+        if keystream(seed, cipher_text.len())[offset..] == target[..] {
+            return Ok(seed as u16);
+        }
+    }
+    Err(Error::SearchExhausted("Unable to find stream cipher key"))
+}
+
+pub fn crack_token<T>(token: T, seeds: RangeInclusive<u32>) -> Result<u32>
+where
+    T: AsRef<[u8]>,
+{
+    let token = token.as_ref();
+    if token.is_empty() {
+        return Err(Error::NotEnoughData(0));
+    }
+
+    for seed in seeds {
+        if keystream(seed, token.len()) == token {
+            return Ok(seed);
+        }
+    }
+    Err(Error::SearchExhausted("Unable to find token seed"))
+}
+
+// Was the token generated by an MT19937 seeded with the time in the last `seconds`?
+pub fn is_recent_timestamp_token<T>(token: T, seconds: u32) -> bool
+where
+    T: AsRef<[u8]>,
+{
+    let now = unix_timestamp();
+    crack_token(token, now.saturating_sub(seconds)..=now).is_ok()
+}
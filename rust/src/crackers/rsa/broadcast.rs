@@ -0,0 +1,34 @@
+use num_traits::ToPrimitive;
+
+use crate::math::bigint::{crt, nth_root, BigUint};
+use crate::pubkey::rsa::PublicKey;
+use crate::Error;
+use crate::Result;
+
+// Example arguments: [(PublicKey, BigUint); 3]
+// The same message encrypted unpadded to e keys sharing that e, as (key,
+// cipher text) pairs. CRT gives m^e mod the product of the moduli, which is
+// just m^e as m is below every modulus, so the e-th root is m (Cryptopals 40)
+pub fn crack(pairs: &[(PublicKey, BigUint)]) -> Result<BigUint> {
+    let e = match pairs.first() {
+        Some((key, _)) => &key.e,
+        None => return Err(Error::NotEnoughData(0)),
+    };
+    let exponent = match e.to_u32() {
+        Some(x) if x as usize <= pairs.len() => x,
+        _ => return Err(Error::NotEnoughData(pairs.len())),
+    };
+
+    let residues: Vec<(BigUint, BigUint)> = pairs
+        .iter()
+        .take(exponent as usize)
+        .map(|(key, cipher_text)| (cipher_text.clone(), key.n.clone()))
+        .collect();
+    let power = crt(&residues)?;
+
+    let output = nth_root(&power, exponent)?;
+    if output.pow(exponent) != power {
+        return Err(Error::SearchExhausted("Not the same message under one e"));
+    }
+    Ok(output)
+}
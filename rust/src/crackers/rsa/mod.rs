@@ -0,0 +1,2 @@
+pub mod broadcast;
+pub mod unpadded_oracle;
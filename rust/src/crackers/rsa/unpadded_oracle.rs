@@ -0,0 +1,48 @@
+use num_bigint::RandBigInt;
+use rand::prelude::*;
+
+use crate::math::bigint::{mod_inverse, BigUint};
+use crate::pubkey::rsa::PublicKey;
+use crate::Error;
+use crate::Result;
+
+pub trait DecryptionOracle {
+    // None when the oracle won't decrypt it
+    fn decrypt(&self, cipher_text: &BigUint) -> Option<BigUint>;
+}
+
+impl<F> DecryptionOracle for F
+where
+    F: Fn(&BigUint) -> Option<BigUint>,
+{
+    fn decrypt(&self, cipher_text: &BigUint) -> Option<BigUint> {
+        self(cipher_text)
+    }
+}
+
+// Example arguments: Oracle, PublicKey, BigUint
+// Asks for s^e * c to be decrypted instead, a different cipher text whose
+// plain text is s * m, then divides out s (Cryptopals 41)
+pub fn crack<O>(oracle: &O, public_key: &PublicKey, cipher_text: &BigUint) -> Result<BigUint>
+where
+    O: DecryptionOracle + ?Sized,
+{
+    let n = &public_key.n;
+    if *n <= BigUint::from(2u32) {
+        return Err(Error::InvalidKey("Modulus below 3"));
+    }
+
+    let mut rng = thread_rng();
+    let (s, s_inv) = loop {
+        let s = rng.gen_biguint_range(&BigUint::from(2u32), n);
+        if let Ok(x) = mod_inverse(&s, n) {
+            break (s, x);
+        }
+    };
+
+    let disguised = public_key.encrypt(&s) * cipher_text % n;
+    match oracle.decrypt(&disguised) {
+        Some(x) => Ok(x * s_inv % n),
+        None => Err(Error::Protocol("Oracle refused to decrypt")),
+    }
+}
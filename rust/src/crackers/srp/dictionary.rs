@@ -0,0 +1,108 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use num_traits::One;
+use rand::prelude::*;
+
+use crate::math::bigint::BigUint;
+use crate::protocols::srp::exchange::{private_key, proof, session_key, Parameters};
+use crate::protocols::srp::login::{Packet, Responder};
+use crate::Error;
+use crate::Result;
+
+const SALT_SIZE: usize = 16;
+
+// Poses as a simplified SRP server with b = 1, B = g and u = 1, so the
+// client's secret is A * g^x mod N and the proof it sends can be checked
+// against guessed passwords offline (Cryptopals 38)
+pub struct FakeServer {
+    parameters: Parameters,
+    salt: Vec<u8>,
+    pub username: Option<String>,
+    pub public_key: Option<BigUint>, // The client's A
+    pub proof: Option<Vec<u8>>,
+}
+
+impl FakeServer {
+    pub fn new(parameters: Parameters) -> FakeServer {
+        FakeServer {
+            parameters,
+            salt: thread_rng().gen::<[u8; SALT_SIZE]>().to_vec(),
+            username: None,
+            public_key: None,
+            proof: None,
+        }
+    }
+
+    pub fn respond(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::Hello {
+                username,
+                public_key,
+            } => {
+                self.username = Some(username);
+                self.public_key = Some(public_key);
+                Ok(Packet::SimpleChallenge {
+                    salt: self.salt.clone(),
+                    public_key: self.parameters.g.clone(),
+                    u: BigUint::one(),
+                })
+            }
+            Packet::Proof(x) => {
+                self.proof = Some(x);
+                Ok(Packet::Denied)
+            }
+            _ => Err(Error::Protocol("Servers don't get sent that")),
+        }
+    }
+}
+
+impl Responder for FakeServer {
+    fn respond(&mut self, packet: Packet) -> Result<Packet> {
+        FakeServer::respond(self, packet)
+    }
+}
+
+// Example arguments: FakeServer, ["password", "letmein"]
+// Tries every word against what the server captured
+pub fn crack<W, P>(server: &FakeServer, words: W) -> Result<Vec<u8>>
+where
+    W: IntoIterator<Item = P>,
+    P: AsRef<[u8]>,
+{
+    let (public_key, expected) = match (&server.public_key, &server.proof) {
+        (Some(x), Some(y)) => (x, y),
+        _ => return Err(Error::Protocol("No login captured")),
+    };
+    let Parameters { n, g, .. } = &server.parameters;
+
+    for word in words {
+        let x = private_key(&server.salt, &word);
+        let secret = public_key * g.modpow(&x, n) % n;
+        if &proof(session_key(&secret), &server.salt) == expected {
+            return Ok(word.as_ref().to_vec());
+        }
+    }
+    Err(Error::SearchExhausted("Password not in the word list"))
+}
+
+// Example arguments: FakeServer, "/tmp/words.txt"
+// One word per line, blank lines are skipped
+pub fn crack_file<P>(server: &FakeServer, path: P) -> Result<Vec<u8>>
+where
+    P: AsRef<Path>,
+{
+    let file = BufReader::new(File::open(path)?);
+    let mut words = Vec::new();
+    for line in file.split(b'\n') {
+        let mut word = line?;
+        if word.last() == Some(&b'\r') {
+            word.pop();
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    crack(server, words)
+}
@@ -0,0 +1,38 @@
+use num_traits::Zero;
+
+use crate::math::bigint::BigUint;
+use crate::protocols::srp::exchange::{proof, session_key, Parameters};
+use crate::protocols::srp::login::{Packet, Responder};
+use crate::Error;
+use crate::Result;
+
+// Example arguments: Server, Parameters::nist(), "alice", 2
+// Logs in as the user with A = multiple * N. The server's secret is then
+// (A * v^u)^b mod N = 0 whatever the password, so K = SHA256(0) (Cryptopals 37)
+pub fn login<R, U>(
+    server: &mut R,
+    parameters: &Parameters,
+    username: U,
+    multiple: u32,
+) -> Result<bool>
+where
+    R: Responder + ?Sized,
+    U: AsRef<str>,
+{
+    let hello = Packet::Hello {
+        username: username.as_ref().to_string(),
+        public_key: &parameters.n * multiple,
+    };
+
+    let salt = match server.respond(hello)? {
+        Packet::Challenge { salt, .. } | Packet::SimpleChallenge { salt, .. } => salt,
+        Packet::Denied => return Ok(false),
+        _ => return Err(Error::Protocol("Expected a challenge")),
+    };
+
+    let key = session_key(&BigUint::zero());
+    match server.respond(Packet::Proof(proof(key, salt)))? {
+        Packet::Ok => Ok(true),
+        _ => Ok(false),
+    }
+}
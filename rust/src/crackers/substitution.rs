@@ -0,0 +1,116 @@
+use rand::prelude::*;
+
+use crate::ciphers::substitution::{decrypt, invert_key, Key, IDENTITY_KEY};
+use crate::text_scoring::{Quadgrams, Scorer};
+
+// Tuned for scorers averaging per letter like the quadgram one, a worse swap
+// is taken with a chance of e^(change / temperature) while it cools down
+const ANNEALING_STEPS: usize = 10000;
+const START_TEMPERATURE: f64 = 0.05;
+
+pub struct CrackResult {
+    pub score: f64,
+    pub key: Key,
+    pub plain_text: Vec<u8>,
+}
+
+// Example arguments: Vec<u8>, 5
+pub fn crack<C>(cipher_text: C, restarts: usize) -> CrackResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_with(&Quadgrams, cipher_text, restarts)
+}
+
+// Climbs from a random key once per restart, keeping the best climb.
+// Letters missing from the cipher text can't be told apart in the key
+pub fn crack_with<S, C>(scorer: &S, cipher_text: C, restarts: usize) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let letters: Vec<u8> = cipher_text
+        .iter()
+        .filter(|x| x.is_ascii_alphabetic())
+        .map(|x| x.to_ascii_uppercase())
+        .collect();
+
+    let mut rng = thread_rng();
+    let mut output = CrackResult {
+        score: f64::NEG_INFINITY,
+        key: IDENTITY_KEY,
+        plain_text: vec![],
+    };
+    for _ in 0..restarts.max(1) {
+        let mut key = IDENTITY_KEY;
+        key.shuffle(&mut rng);
+
+        let (key, score) = climb(scorer, &letters, key, &mut rng);
+        if score > output.score {
+            output.score = score;
+            output.key = key;
+        }
+    }
+
+    // Shuffles of the identity key are always valid keys
+    output.key = invert_key(output.key).unwrap();
+    output.plain_text = cipher_text.to_vec();
+    decrypt(&mut output.plain_text, output.key).unwrap();
+    output
+}
+
+// Simulated annealing over random swaps of letters in the decryption key,
+// finished off by hill climbing
+fn climb<S, R>(scorer: &S, letters: &[u8], mut key: Key, rng: &mut R) -> (Key, f64)
+where
+    S: Scorer + ?Sized,
+    R: Rng,
+{
+    let score = |key: &Key| {
+        let plain_text: Vec<u8> = letters.iter().map(|x| key[(x - b'A') as usize]).collect();
+        scorer.score(&plain_text)
+    };
+
+    let mut current = score(&key);
+    let mut best = current;
+    let mut best_key = key;
+    for step in 0..ANNEALING_STEPS {
+        let temperature = START_TEMPERATURE * (1.0 - step as f64 / ANNEALING_STEPS as f64);
+        let i = rng.gen_range(0, key.len());
+        let j = rng.gen_range(0, key.len());
+        key.swap(i, j);
+
+        let new = score(&key);
+        if new > current || rng.gen::<f64>() < ((new - current) / temperature).exp() {
+            current = new;
+            if current > best {
+                best = current;
+                best_key = key;
+            }
+        } else {
+            key.swap(i, j);
+        }
+    }
+
+    // Then make sure no single swap is left that helps
+    key = best_key;
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..key.len() {
+            for j in i + 1..key.len() {
+                key.swap(i, j);
+
+                let new = score(&key);
+                if new > best {
+                    best = new;
+                    improved = true;
+                } else {
+                    key.swap(i, j);
+                }
+            }
+        }
+    }
+    (key, best)
+}
@@ -0,0 +1,124 @@
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+
+use crate::crackers::hash::length_extension::MacOracle;
+use crate::Error;
+use crate::Result;
+
+const SHORTLIST: usize = 8; // Best bytes from the first pass that get sampled again
+const MAX_RETRIES: usize = 20;
+
+// Time taken by every signature tried at a position, as medians
+struct Timings {
+    best: u8,
+    best_time: Duration,
+    baseline: Duration, // What a wrong byte costs
+}
+
+impl Timings {
+    fn step(&self) -> Duration {
+        self.best_time.saturating_sub(self.baseline)
+    }
+}
+
+// Example arguments: Oracle, b"file", 20, 3
+// Finds a signature byte by byte, the right byte taking longest to be
+// rejected. The likely bytes are timed `samples` times and the median used so
+// the odd slow call doesn't matter
+pub fn crack<O, F>(oracle: &O, file: F, mac_size: usize, samples: usize) -> Result<Vec<u8>>
+where
+    O: MacOracle + ?Sized,
+    F: AsRef<[u8]>,
+{
+    let file = file.as_ref();
+    let samples = samples.max(1);
+
+    let mut signature = vec![0; mac_size];
+    let mut found: Vec<Timings> = Vec::with_capacity(mac_size);
+    let mut retries = 0;
+    while found.len() < mac_size {
+        let position = found.len();
+        let timings = match find_byte(oracle, file, &mut signature, position, samples) {
+            Some(x) => x,
+            None => return Ok(signature), // The oracle accepted it
+        };
+
+        // Once a byte is right every signature tried next costs about what it
+        // did, if they don't the last byte was a fluke
+        let fluke = match found.last() {
+            Some(last) => timings.baseline < last.baseline + last.step() / 2,
+            None => false,
+        };
+        // The right byte stands out about as much as the first one did, when
+        // none does it was unlucky in the first pass
+        let unsure = match found.first() {
+            Some(first) => timings.step() < first.step() / 2,
+            None => false,
+        };
+
+        if fluke || unsure || position == mac_size - 1 {
+            retries += 1;
+            if retries > MAX_RETRIES {
+                return Err(Error::SearchExhausted("Unable to find signature"));
+            }
+            if fluke || position == mac_size - 1 {
+                found.pop();
+            }
+            continue;
+        }
+
+        signature[position] = timings.best;
+        found.push(timings);
+    }
+    Err(Error::SearchExhausted("Unable to find signature"))
+}
+
+// Times every byte at the position once, then the slowest few `samples` more
+// times. None when the oracle accepted a signature, which is left in place
+fn find_byte<O>(
+    oracle: &O,
+    file: &[u8],
+    signature: &mut [u8],
+    position: usize,
+    samples: usize,
+) -> Option<Timings>
+where
+    O: MacOracle + ?Sized,
+{
+    let mut times: Vec<Vec<Duration>> = vec![vec![]; 256];
+    let mut candidates: Vec<u8> = (0..=255).collect();
+
+    for pass in 0..2 {
+        let repeats = if pass == 0 { 1 } else { samples };
+        for &x in &candidates {
+            signature[position] = x;
+            for _ in 0..repeats {
+                let start = Instant::now();
+                if oracle.verify(file, signature) {
+                    return None;
+                }
+                times[x as usize].push(start.elapsed());
+            }
+        }
+
+        candidates.sort_by_key(|&x| Reverse(median(&times[x as usize])));
+        if pass == 0 {
+            candidates.truncate(SHORTLIST);
+        }
+    }
+
+    let mut medians: Vec<Duration> = times.iter().map(|x| median(x)).collect();
+    medians.sort();
+    Some(Timings {
+        best: candidates[0],
+        best_time: median(&times[candidates[0] as usize]),
+        baseline: medians[medians.len() / 2],
+    })
+}
+
+// The lower of the middle two on an even count, delays only ever add time
+fn median(times: &[Duration]) -> Duration {
+    let mut times = times.to_vec();
+    times.sort();
+    times[(times.len() - 1) / 2]
+}
@@ -0,0 +1,292 @@
+use rand::prelude::*;
+
+use crate::ciphers::transposition::{columnar_decrypt_with_order, rail_fence_decrypt};
+use crate::text_scoring::{Bigrams, Scorer};
+
+// Column counts up to this many have every order tried instead of climbing
+const MAX_EXHAUSTIVE_COLUMNS: usize = 7;
+
+// As for substitution, a worse move is taken with a chance of
+// e^(change / temperature) while it cools down
+const ANNEALING_STEPS: usize = 2000;
+const START_TEMPERATURE: f64 = 0.05;
+
+pub struct ColumnarResult {
+    pub score: f64,
+    pub order: Vec<usize>,
+    pub plain_text: Vec<u8>,
+}
+
+pub struct RailFenceResult {
+    pub score: f64,
+    pub rails: usize,
+    pub plain_text: Vec<u8>,
+}
+
+pub fn crack_rail_fence<C>(cipher_text: C, max_rails: usize) -> RailFenceResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_rail_fence_with(&Bigrams, cipher_text, max_rails)
+}
+
+pub fn crack_rail_fence_with<S, C>(scorer: &S, cipher_text: C, max_rails: usize) -> RailFenceResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output = RailFenceResult {
+        score: f64::NEG_INFINITY,
+        rails: 1,
+        plain_text: cipher_text.to_vec(),
+    };
+
+    for rails in 2..=max_rails {
+        let mut plain_text = cipher_text.to_vec();
+        rail_fence_decrypt(&mut plain_text, rails).unwrap();
+
+        let score = scorer.score(&plain_text);
+        if score > output.score {
+            output.score = score;
+            output.rails = rails;
+            output.plain_text = plain_text;
+        }
+    }
+    output
+}
+
+// Example arguments: Vec<u8>, 12, 20
+// Rotations of the order score about the same, so unlike crack_columnar_with
+// this picks between them by which reads most like whole English sentences
+pub fn crack_columnar<C>(cipher_text: C, max_columns: usize, restarts: usize) -> ColumnarResult
+where
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output = crack_columnar_with(&Bigrams, cipher_text, max_columns, restarts);
+
+    output.order = unrotate(cipher_text, &output.order);
+    output.plain_text = cipher_text.to_vec();
+    columnar_decrypt_with_order(&mut output.plain_text, &output.order).unwrap();
+    output.score = Bigrams.score(&output.plain_text);
+    output
+}
+
+// Every column count up to `max_columns`, trying every order of the small
+// ones and annealing then hill climbing the rest from `restarts` random
+// orders
+pub fn crack_columnar_with<S, C>(
+    scorer: &S,
+    cipher_text: C,
+    max_columns: usize,
+    restarts: usize,
+) -> ColumnarResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output = ColumnarResult {
+        score: f64::NEG_INFINITY,
+        order: vec![0],
+        plain_text: cipher_text.to_vec(),
+    };
+
+    let mut rng = thread_rng();
+    for columns in 2..=max_columns.min(cipher_text.len()) {
+        // Every order tried is a permutation, so decrypting can't fail
+        let score = |order: &[usize]| {
+            let mut plain_text = cipher_text.to_vec();
+            columnar_decrypt_with_order(&mut plain_text, order).unwrap();
+            scorer.score(&plain_text)
+        };
+
+        let (order, score) = if columns <= MAX_EXHAUSTIVE_COLUMNS {
+            permutations(columns)
+                .into_iter()
+                .map(|x| {
+                    let score = score(&x);
+                    (x, score)
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap()
+        } else {
+            (0..restarts.max(1))
+                .map(|_| {
+                    let mut order: Vec<usize> = (0..columns).collect();
+                    order.shuffle(&mut rng);
+                    climb(&score, anneal(&score, order, &mut rng))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap()
+        };
+
+        if score > output.score {
+            output.score = score;
+            output.order = order;
+        }
+    }
+
+    columnar_decrypt_with_order(&mut output.plain_text, &output.order).unwrap();
+    output.score = scorer.score(&output.plain_text);
+    output
+}
+
+// Adding to every column's place in the order can rotate the plain text,
+// which with a short last row still encrypts to the same cipher text, give or
+// take the row that wraps round. Little but where the text starts tells those
+// apart, so take the rotation reading most like whole sentences
+fn unrotate(cipher_text: &[u8], order: &[usize]) -> Vec<usize> {
+    let columns = order.len();
+    let mut plain_text = cipher_text.to_vec();
+    columnar_decrypt_with_order(&mut plain_text, order).unwrap();
+
+    let mut output = order.to_vec();
+    let mut best = sentence_ends(&plain_text);
+    for shift in 1..columns {
+        let rotated: Vec<usize> = order.iter().map(|x| (x + shift) % columns).collect();
+        let mut candidate = cipher_text.to_vec();
+        columnar_decrypt_with_order(&mut candidate, &rotated).unwrap();
+
+        if is_rotation(&plain_text, &candidate, columns) && sentence_ends(&candidate) > best {
+            best = sentence_ends(&candidate);
+            output = rotated;
+        }
+    }
+    output
+}
+
+// Whether some rotation of `a` differs from `b` in at most `tolerance` bytes
+fn is_rotation(a: &[u8], b: &[u8], tolerance: usize) -> bool {
+    (0..a.len()).any(|shift| {
+        let differences = b
+            .iter()
+            .enumerate()
+            .filter(|&(i, x)| a[(i + shift) % a.len()] != *x)
+            .take(tolerance + 1)
+            .count();
+        differences <= tolerance
+    })
+}
+
+// One each for starting with a capital or digit and ending with a full stop,
+// ignoring quotes and whitespace
+fn sentence_ends(text: &[u8]) -> usize {
+    let is_edge = |x: &&u8| x.is_ascii_whitespace() || b"\"'".contains(x);
+    let first = text.iter().find(|x| !is_edge(x));
+    let last = text.iter().rev().find(|x| !is_edge(x));
+
+    let starts = first.is_some_and(|x| x.is_ascii_uppercase() || x.is_ascii_digit());
+    let ends = last.is_some_and(|x| b".!?".contains(x));
+    starts as usize + ends as usize
+}
+
+// Swaps pairs and moves runs of columns while it helps. Works on where each
+// plain text column is read from, so a moved run keeps its text lined up
+fn climb<F>(score: &F, order: Vec<usize>) -> (Vec<usize>, f64)
+where
+    F: Fn(&[usize]) -> f64,
+{
+    let score_ranks = |ranks: &[usize]| score(&invert(ranks));
+    let mut ranks = invert(&order);
+    let mut best = score_ranks(&ranks);
+    let columns = ranks.len();
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        let mut candidates = vec![];
+        for i in 0..columns {
+            for j in i + 1..columns {
+                let mut swapped = ranks.clone();
+                swapped.swap(i, j);
+                candidates.push(swapped);
+            }
+            for size in 1..columns {
+                for j in 0..=columns - size {
+                    if i + size > columns || i == j {
+                        continue;
+                    }
+                    let mut moved = ranks.clone();
+                    let run: Vec<usize> = moved.drain(i..i + size).collect();
+                    let j = j.min(moved.len());
+                    moved.splice(j..j, run);
+                    candidates.push(moved);
+                }
+            }
+        }
+
+        for candidate in candidates {
+            let new = score_ranks(&candidate);
+            if new > best {
+                best = new;
+                ranks = candidate;
+                improved = true;
+            }
+        }
+    }
+    (invert(&ranks), best)
+}
+
+// Random swaps and run moves of the ranks, so it can get past orders where
+// the short columns are in the wrong places
+fn anneal<F, R>(score: &F, order: Vec<usize>, rng: &mut R) -> Vec<usize>
+where
+    F: Fn(&[usize]) -> f64,
+    R: Rng,
+{
+    let score_ranks = |ranks: &[usize]| score(&invert(ranks));
+    let mut ranks = invert(&order);
+    let mut current = score_ranks(&ranks);
+    let mut best = (ranks.clone(), current);
+    let columns = ranks.len();
+
+    for step in 0..ANNEALING_STEPS {
+        let temperature = START_TEMPERATURE * (1.0 - step as f64 / ANNEALING_STEPS as f64);
+        let mut candidate = ranks.clone();
+        let i = rng.gen_range(0, columns);
+        if rng.gen() {
+            candidate.swap(i, rng.gen_range(0, columns));
+        } else {
+            let size = rng.gen_range(1, columns - i + 1);
+            let run: Vec<usize> = candidate.drain(i..i + size).collect();
+            let j = rng.gen_range(0, candidate.len() + 1);
+            candidate.splice(j..j, run);
+        }
+
+        let new = score_ranks(&candidate);
+        if new > current || rng.gen::<f64>() < ((new - current) / temperature).exp() {
+            current = new;
+            ranks = candidate;
+            if current > best.1 {
+                best = (ranks.clone(), current);
+            }
+        }
+    }
+    invert(&best.0)
+}
+
+fn invert(permutation: &[usize]) -> Vec<usize> {
+    let mut output = vec![0; permutation.len()];
+    for (i, &x) in permutation.iter().enumerate() {
+        output[x] = i;
+    }
+    output
+}
+
+fn permutations(size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+
+    let mut output = vec![];
+    for permutation in permutations(size - 1) {
+        for i in 0..=permutation.len() {
+            let mut x = permutation.clone();
+            x.insert(i, size - 1);
+            output.push(x);
+        }
+    }
+    output
+}
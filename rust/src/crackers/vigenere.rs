@@ -0,0 +1,98 @@
+use crate::ciphers::caesar::{Shift, ALPHABET_SIZE};
+use crate::ciphers::vigenere::crypt;
+use crate::crackers::caesar::crack_with as caesar_cracker;
+use crate::text_scoring::{Frequency, Scorer};
+
+// Key lengths whose coincidence is this close to the best are as good, so
+// the shortest of them wins over its multiples
+const KEY_LENGTH_TOLERANCE: f64 = 0.9;
+
+#[derive(Debug)]
+pub struct CrackResult {
+    pub score: f64,
+    pub key: Vec<u8>,
+    pub plain_text: Vec<u8>,
+}
+
+pub fn crack<C>(cipher_text: C, max_key_length: usize) -> CrackResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_with(&Frequency, cipher_text, max_key_length)
+}
+
+pub fn crack_with<S, C>(scorer: &S, cipher_text: C, max_key_length: usize) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let letters: Vec<u8> = cipher_text
+        .iter()
+        .filter(|x| x.is_ascii_alphabetic())
+        .copied()
+        .collect();
+
+    let key_length = detect_key_length(&letters, max_key_length);
+    let key: Vec<u8> = transpose(&letters, key_length)
+        .iter()
+        .map(|column| {
+            let shift = caesar_cracker(scorer, column).key % ALPHABET_SIZE;
+            b'A' + (ALPHABET_SIZE - shift) % ALPHABET_SIZE
+        })
+        .collect();
+
+    let mut plain_text = cipher_text.to_vec();
+    // Always a letter for each of at least one column
+    crypt(&mut plain_text, &key, Shift::Left).unwrap();
+    CrackResult {
+        score: scorer.score(&plain_text),
+        key,
+        plain_text,
+    }
+}
+
+// Average index of coincidence of the key columns, English is around 0.066
+// and random letters 0.038
+pub fn index_of_coincidence<L>(letters: L, key_length: usize) -> f64
+where
+    L: AsRef<[u8]>,
+{
+    let mut total = 0.0;
+    for column in transpose(letters.as_ref(), key_length) {
+        let mut counts = [0usize; ALPHABET_SIZE as usize];
+        for x in &column {
+            counts[(x.to_ascii_uppercase() - b'A') as usize] += 1;
+        }
+
+        let size = column.len();
+        if size > 1 {
+            let pairs: usize = counts.iter().map(|&x| x * x.saturating_sub(1)).sum();
+            total += pairs as f64 / (size * (size - 1)) as f64;
+        }
+    }
+    total / key_length as f64
+}
+
+fn detect_key_length(letters: &[u8], max_key_length: usize) -> usize {
+    let max_key_length = max_key_length.min(letters.len() / 2).max(1);
+    let scores: Vec<f64> = (1..=max_key_length)
+        .map(|x| index_of_coincidence(letters, x))
+        .collect();
+
+    let best = scores.iter().cloned().fold(0.0, f64::max);
+    scores
+        .iter()
+        .position(|&x| x >= best * KEY_LENGTH_TOLERANCE)
+        .unwrap_or(0)
+        + 1
+}
+
+fn transpose(letters: &[u8], key_length: usize) -> Vec<Vec<u8>> {
+    let mut output = vec![vec![]; key_length];
+
+    for (i, &x) in letters.iter().enumerate() {
+        output[i % key_length].push(x);
+    }
+    output
+}
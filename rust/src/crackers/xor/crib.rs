@@ -0,0 +1,145 @@
+use crate::ciphers::xor::repeating_bytes::crypt;
+use crate::text_scoring::{Frequency, Scorer};
+use crate::utils::xor;
+
+// A period has to repeat at least this many bytes of a fragment to be trusted
+const MIN_PERIOD_CHECKS: usize = 4;
+
+pub struct Fragment {
+    pub offset: usize,
+    pub key: Vec<u8>, // The key bytes at offset, if the crib is there
+}
+
+pub struct KeyResult {
+    pub score: f64,
+    pub period: usize,
+    pub key: Vec<u8>, // Aligned to the start of the cipher text
+    pub offsets: Vec<usize>,
+    pub plain_text: Vec<u8>,
+}
+
+pub struct PairFragment {
+    pub score: f64,
+    pub offset: usize,
+    pub plain_text: Vec<u8>, // The other message at offset, if one has the crib there
+}
+
+// Every offset the crib fits at, in order
+pub fn drag<C, P>(cipher_text: C, crib: P) -> Vec<Fragment>
+where
+    C: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let crib = crib.as_ref();
+    if crib.is_empty() || crib.len() > cipher_text.len() {
+        return vec![];
+    }
+
+    cipher_text
+        .windows(crib.len())
+        .enumerate()
+        .map(|(offset, window)| {
+            let mut key = window.to_vec();
+            xor(&mut key, crib);
+            Fragment { offset, key }
+        })
+        .collect()
+}
+
+// The smallest period the fragment repeats with, if it repeats enough to tell
+pub fn fragment_period<F>(fragment: F) -> Option<usize>
+where
+    F: AsRef<[u8]>,
+{
+    let fragment = fragment.as_ref();
+
+    (1..fragment.len())
+        .take_while(|&period| fragment.len() - period >= MIN_PERIOD_CHECKS)
+        .find(|&period| (period..fragment.len()).all(|i| fragment[i] == fragment[i - period]))
+}
+
+pub fn recover_key<C, P>(cipher_text: C, crib: P) -> Vec<KeyResult>
+where
+    C: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    recover_key_with(&Frequency, cipher_text, crib)
+}
+
+// Keys rebuilt from every fragment that repeats, best plain text first. Offsets
+// that rebuild the same key are merged together
+pub fn recover_key_with<S, C, P>(scorer: &S, cipher_text: C, crib: P) -> Vec<KeyResult>
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let mut output: Vec<KeyResult> = vec![];
+
+    for fragment in drag(cipher_text, crib) {
+        let period = match fragment_period(&fragment.key) {
+            Some(x) => x,
+            None => continue,
+        };
+
+        let mut key = vec![0; period];
+        for (i, &x) in fragment.key[..period].iter().enumerate() {
+            key[(fragment.offset + i) % period] = x;
+        }
+
+        if let Some(found) = output.iter_mut().find(|x| x.key == key) {
+            found.offsets.push(fragment.offset);
+            continue;
+        }
+
+        let mut plain_text = cipher_text.to_vec();
+        crypt(&mut plain_text, &key);
+        output.push(KeyResult {
+            score: scorer.score(&plain_text),
+            period,
+            key,
+            offsets: vec![fragment.offset],
+            plain_text,
+        });
+    }
+
+    output.sort_by(|a, b| b.score.total_cmp(&a.score));
+    output
+}
+
+pub fn drag_pair<L, R, P>(left: L, right: R, crib: P) -> Vec<PairFragment>
+where
+    L: AsRef<[u8]>,
+    R: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    drag_pair_with(&Frequency, left, right, crib)
+}
+
+// Two cipher texts sharing a keystream XOR to their two plain texts XORed, so
+// a crib in one reveals the other. Best looking fragments first
+pub fn drag_pair_with<S, L, R, P>(scorer: &S, left: L, right: R, crib: P) -> Vec<PairFragment>
+where
+    S: Scorer + ?Sized,
+    L: AsRef<[u8]>,
+    R: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    let mut plain_texts = left.as_ref().to_vec();
+    plain_texts.truncate(right.as_ref().len());
+    xor(&mut plain_texts, right);
+
+    let mut output: Vec<PairFragment> = drag(plain_texts, crib)
+        .into_iter()
+        .map(|x| PairFragment {
+            score: scorer.score(&x.key),
+            offset: x.offset,
+            plain_text: x.key,
+        })
+        .collect();
+
+    output.sort_by(|a, b| b.score.total_cmp(&a.score));
+    output
+}
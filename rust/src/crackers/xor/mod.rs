@@ -1,2 +1,3 @@
+pub mod crib;
 pub mod repeating_bytes;
 pub mod single_byte;
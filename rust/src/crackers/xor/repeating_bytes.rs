@@ -5,14 +5,24 @@ use std::path::Path;
 
 use base64::decode as b64_decode;
 
-use crate::crackers::xor::single_byte::crack as single_byte_cracker;
-use crate::detectors::xor::repeating_bytes::detect_keysizes;
+use crate::ciphers::xor::repeating_bytes::crypt;
+use crate::crackers::xor::single_byte::crack_ranked_with as single_byte_cracker;
+use crate::detectors::xor::repeating_bytes::{detect_keysizes_with, Strategy};
+use crate::text_scoring::{Frequency, Scorer};
+use crate::Result;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+const RUNNER_UPS: usize = 3;
+
+#[derive(Clone)]
+pub struct RunnerUp {
+    pub key: u8,
+    pub margin: f64, // How far its column score fell behind the chosen byte's
+}
 
 pub struct CrackResult {
-    pub score: usize,
+    pub score: f64,
     pub key: Vec<u8>,
+    pub runner_ups: Vec<Vec<RunnerUp>>, // Per key column, best first
 }
 
 // Example arguments: Vec<u8>, 2, 40, 5, 3
@@ -25,35 +35,92 @@ pub fn crack<C>(
 ) -> CrackResult
 where
     C: AsRef<[u8]>,
+{
+    crack_with(
+        &Frequency,
+        cipher_text,
+        min_key_size,
+        max_key_size,
+        samples,
+        check_top_x_key_sizes,
+    )
+}
+
+pub fn crack_with<S, C>(
+    scorer: &S,
+    cipher_text: C,
+    min_key_size: usize,
+    max_key_size: usize,
+    samples: usize,
+    check_top_x_key_sizes: usize,
+) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    crack_with_strategy(
+        scorer,
+        Strategy::Hamming { samples },
+        cipher_text,
+        min_key_size,
+        max_key_size,
+        check_top_x_key_sizes,
+    )
+}
+
+pub fn crack_with_strategy<S, C>(
+    scorer: &S,
+    strategy: Strategy,
+    cipher_text: C,
+    min_key_size: usize,
+    max_key_size: usize,
+    check_top_x_key_sizes: usize,
+) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
 {
     let cipher_text = cipher_text.as_ref();
     let mut output = CrackResult {
-        score: 0,
+        score: f64::NEG_INFINITY,
         key: vec![],
+        runner_ups: vec![],
     };
 
-    let keysizes = detect_keysizes(
+    let keysizes = detect_keysizes_with(
+        strategy,
         cipher_text,
         min_key_size,
         max_key_size,
-        samples,
         check_top_x_key_sizes,
     );
 
     for keysize in keysizes {
         let cipher_text_transposed = transpose(cipher_text, keysize.size);
 
-        let mut score = 0;
+        let mut score = 0.0;
         let mut key = Vec::with_capacity(keysize.size);
-        for i in 0..keysize.size {
-            let result = single_byte_cracker(&cipher_text_transposed[i]);
-            key.push(result.key);
-            score += result.score;
+        let mut runner_ups = Vec::with_capacity(keysize.size);
+        for column in &cipher_text_transposed {
+            let results = single_byte_cracker(scorer, column, RUNNER_UPS + 1);
+            key.push(results[0].key);
+            score += results[0].score;
+            runner_ups.push(
+                results[1..]
+                    .iter()
+                    .map(|x| RunnerUp {
+                        key: x.key,
+                        margin: results[0].score - x.score,
+                    })
+                    .collect(),
+            );
         }
+        score /= keysize.size as f64; // Per column scores so key sizes can be compared
 
         if score > output.score {
             output.score = score;
             output.key = key;
+            output.runner_ups = runner_ups;
         }
     }
     output
@@ -69,16 +136,38 @@ pub fn crack_file<P>(
 ) -> Result<CrackResult>
 where
     P: AsRef<Path>,
+{
+    crack_file_with(
+        &Frequency,
+        path,
+        min_key_size,
+        max_key_size,
+        samples,
+        check_top_x_key_sizes,
+    )
+}
+
+pub fn crack_file_with<S, P>(
+    scorer: &S,
+    path: P,
+    min_key_size: usize,
+    max_key_size: usize,
+    samples: usize,
+    check_top_x_key_sizes: usize,
+) -> Result<CrackResult>
+where
+    S: Scorer + ?Sized,
+    P: AsRef<Path>,
 {
     let file = BufReader::new(File::open(path)?);
 
     let data = b64_decode(
         file.bytes()
-            .map(|x| x.unwrap())
-            .filter(|&x| x != b'\n')
-            .collect::<Vec<u8>>(),
+            .filter(|x| !matches!(x, Ok(b'\n')))
+            .collect::<std::io::Result<Vec<u8>>>()?,
     )?;
-    Ok(crack(
+    Ok(crack_with(
+        scorer,
         data,
         min_key_size,
         max_key_size,
@@ -87,6 +176,80 @@ where
     ))
 }
 
+pub fn crack_runner_ups<C>(cipher_text: C, result: &CrackResult, columns: usize) -> CrackResult
+where
+    C: AsRef<[u8]>,
+{
+    crack_runner_ups_with(&Frequency, cipher_text, result, columns)
+}
+
+// Tries every combination of runner ups in the `columns` closest called key
+// columns, keeping the key whose whole plain text scores best. The runner ups
+// are kept as they were found from the column statistics
+pub fn crack_runner_ups_with<S, C>(
+    scorer: &S,
+    cipher_text: C,
+    result: &CrackResult,
+    columns: usize,
+) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    let cipher_text = cipher_text.as_ref();
+    let score = |key: &[u8]| {
+        let mut plain_text = cipher_text.to_vec();
+        crypt(&mut plain_text, key);
+        scorer.score(&plain_text)
+    };
+
+    let mut uncertain: Vec<usize> = (0..result.key.len())
+        .filter(|&i| !result.runner_ups[i].is_empty())
+        .collect();
+    uncertain.sort_by(|&a, &b| {
+        result.runner_ups[a][0]
+            .margin
+            .total_cmp(&result.runner_ups[b][0].margin)
+    });
+    uncertain.truncate(columns);
+
+    let mut output = CrackResult {
+        score: score(&result.key),
+        key: result.key.clone(),
+        runner_ups: vec![],
+    };
+
+    // Counts through every combination, 0 being the chosen byte for a column
+    let mut choices = vec![0; uncertain.len()];
+    let mut key = result.key.clone();
+    loop {
+        let position = choices
+            .iter()
+            .zip(&uncertain)
+            .position(|(&choice, &column)| choice < result.runner_ups[column].len());
+        let position = match position {
+            Some(x) => x,
+            None => break,
+        };
+        for i in 0..position {
+            choices[i] = 0;
+            key[uncertain[i]] = result.key[uncertain[i]];
+        }
+        choices[position] += 1;
+        let column = uncertain[position];
+        key[column] = result.runner_ups[column][choices[position] - 1].key;
+
+        let score = score(&key);
+        if score > output.score {
+            output.score = score;
+            output.key = key.clone();
+        }
+    }
+
+    output.runner_ups = result.runner_ups.clone();
+    output
+}
+
 fn transpose(cipher_text: &[u8], keysize: usize) -> Vec<Vec<u8>> {
     let inner_capacity = cipher_text.len() / keysize;
     let mut output = vec![Vec::with_capacity(inner_capacity); keysize];
@@ -1,8 +1,8 @@
 use crate::ciphers::xor::single_byte::crypt;
-use crate::text_scoring::common::count as get_score;
+use crate::text_scoring::{Frequency, Scorer};
 
 pub struct CrackResult {
-    pub score: usize,
+    pub score: f64,
     pub key: u8,
     pub plain_text: Vec<u8>,
 }
@@ -10,24 +10,46 @@ pub struct CrackResult {
 pub fn crack<C>(cipher_text: C) -> CrackResult
 where
     C: AsRef<[u8]>,
+{
+    crack_with(&Frequency, cipher_text)
+}
+
+pub fn crack_with<S, C>(scorer: &S, cipher_text: C) -> CrackResult
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
+{
+    crack_ranked_with(scorer, cipher_text, 1).remove(0)
+}
+
+pub fn crack_ranked<C>(cipher_text: C, count: usize) -> Vec<CrackResult>
+where
+    C: AsRef<[u8]>,
+{
+    crack_ranked_with(&Frequency, cipher_text, count)
+}
+
+// The best `count` keys, best first. Ties keep the lower key first
+pub fn crack_ranked_with<S, C>(scorer: &S, cipher_text: C, count: usize) -> Vec<CrackResult>
+where
+    S: Scorer + ?Sized,
+    C: AsRef<[u8]>,
 {
     let cipher_text = cipher_text.as_ref();
-    let mut output = CrackResult {
-        score: 0,
-        key: 0,
-        plain_text: vec![],
-    };
+    let mut output = Vec::with_capacity(256);
 
     for i in 0..=255 {
         let mut plain_text = cipher_text.to_vec();
         crypt(&mut plain_text, i);
 
-        let score = get_score(&plain_text);
-        if score > output.score {
-            output.score = score;
-            output.key = i;
-            output.plain_text = plain_text;
-        }
+        output.push(CrackResult {
+            score: scorer.score(&plain_text),
+            key: i,
+            plain_text,
+        });
     }
+
+    output.sort_by(|a, b| b.score.total_cmp(&a.score));
+    output.truncate(count);
     output
 }
@@ -4,7 +4,7 @@ use std::io::BufRead;
 use std::io::BufReader;
 use std::path::Path;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use crate::Result;
 
 pub struct DetectEntryResult {
     pub score: usize,
@@ -1,19 +1,24 @@
+use std::collections::HashMap;
+
 use crate::utils::hamming_distance;
 
+const KASISKI_NGRAM_SIZE: usize = 3;
+const MULTIPLE_TOLERANCE: f32 = 0.05; // How much worse a divisor may score and still win
+
+// Lower scores are better for every strategy
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Strategy {
+    Hamming { samples: usize },         // Consecutive chunk pairs
+    HammingAllPairs { samples: usize }, // Every pair of the first `samples` chunks
+    IndexOfCoincidence,
+    Kasiski, // Spacing of repeated n-grams
+}
+
 pub struct KeysizeScore {
     pub size: usize,
     pub score: f32,
 }
 
-fn add_keysize_score(list: &mut Vec<KeysizeScore>, max: usize, item: KeysizeScore) {
-    let index = list
-        .binary_search_by(|x| x.score.partial_cmp(&item.score).unwrap())
-        .unwrap_or_else(|x| x);
-
-    list.insert(index, item);
-    list.truncate(max);
-}
-
 pub fn detect_keysizes<C>(
     cipher_text: C,
     min_size: usize,
@@ -21,33 +26,149 @@ pub fn detect_keysizes<C>(
     samples: usize,
     top: usize,
 ) -> Vec<KeysizeScore>
+where
+    C: AsRef<[u8]>,
+{
+    detect_keysizes_with(
+        Strategy::Hamming { samples },
+        cipher_text,
+        min_size,
+        max_size,
+        top,
+    )
+}
+
+pub fn detect_keysizes_with<C>(
+    strategy: Strategy,
+    cipher_text: C,
+    min_size: usize,
+    max_size: usize,
+    top: usize,
+) -> Vec<KeysizeScore>
 where
     C: AsRef<[u8]>,
 {
     let cipher_text = cipher_text.as_ref();
+    let spacings = match strategy {
+        Strategy::Kasiski => kasiski_spacings(cipher_text),
+        _ => vec![],
+    };
 
-    let mut output = vec![];
-    for keysize in min_size..=max_size {
-        let mut score = 0;
-        let mut checked = 0;
+    // Every strategy compares at least two chunks of the key size
+    let max_size = max_size.min(cipher_text.len() / 2);
+    let mut output: Vec<KeysizeScore> = (min_size.max(1)..=max_size)
+        .map(|keysize| KeysizeScore {
+            size: keysize,
+            score: match strategy {
+                Strategy::Hamming { samples } => hamming_score(cipher_text, keysize, samples),
+                Strategy::HammingAllPairs { samples } => {
+                    hamming_all_pairs_score(cipher_text, keysize, samples)
+                }
+                Strategy::IndexOfCoincidence => coincidence_score(cipher_text, keysize),
+                Strategy::Kasiski => kasiski_score(&spacings, keysize),
+            },
+        })
+        .collect();
+
+    output.sort_by(|a, b| a.score.total_cmp(&b.score));
+    collapse_multiples(&mut output);
+    output.truncate(top);
+    output
+}
+
+// A multiple of the real key size lines up just as well, so drop any key size
+// with a divisor scoring about as well as it
+fn collapse_multiples(list: &mut Vec<KeysizeScore>) {
+    let sizes: Vec<(usize, f32)> = list.iter().map(|x| (x.size, x.score)).collect();
+
+    list.retain(|x| {
+        !sizes.iter().any(|&(size, score)| {
+            size < x.size
+                && x.size.is_multiple_of(size)
+                && score <= x.score + x.score.abs() * MULTIPLE_TOLERANCE
+        })
+    });
+}
 
-        let mut chunks = cipher_text.chunks_exact(keysize);
-        while let (Some(x), Some(y)) = (chunks.next(), chunks.next()) {
+fn hamming_score(cipher_text: &[u8], keysize: usize, samples: usize) -> f32 {
+    let mut score = 0;
+    let mut checked = 0;
+
+    let mut chunks = cipher_text.chunks_exact(keysize);
+    while let (Some(x), Some(y)) = (chunks.next(), chunks.next()) {
+        score += hamming_distance(x, y);
+        checked += 1;
+        if checked >= samples {
+            break;
+        }
+    }
+    score as f32 / checked as f32 / keysize as f32
+}
+
+fn hamming_all_pairs_score(cipher_text: &[u8], keysize: usize, samples: usize) -> f32 {
+    let chunks: Vec<&[u8]> = cipher_text
+        .chunks_exact(keysize)
+        .take(samples.max(2))
+        .collect();
+
+    let mut score = 0;
+    let mut checked = 0;
+    for (i, x) in chunks.iter().enumerate() {
+        for y in &chunks[i + 1..] {
             score += hamming_distance(x, y);
             checked += 1;
-            if checked >= samples {
-                break;
-            }
         }
+    }
+    score as f32 / checked as f32 / keysize as f32
+}
 
-        add_keysize_score(
-            &mut output,
-            top,
-            KeysizeScore {
-                size: keysize,
-                score: score as f32 / checked as f32 / keysize as f32,
-            },
-        );
+// Uniform bytes over the average index of coincidence of each key column,
+// so English text lands far below random data's 1
+fn coincidence_score(cipher_text: &[u8], keysize: usize) -> f32 {
+    let mut counts = vec![[0usize; 256]; keysize];
+    for (i, &x) in cipher_text.iter().enumerate() {
+        counts[i % keysize][x as usize] += 1;
+    }
+
+    let mut total = 0.0;
+    let mut columns = 0;
+    for column in &counts {
+        let size: usize = column.iter().sum();
+        if size < 2 {
+            continue;
+        }
+
+        let pairs: usize = column.iter().map(|&x| x * x.saturating_sub(1)).sum();
+        total += pairs as f32 / (size * (size - 1)) as f32;
+        columns += 1;
+    }
+
+    (1.0 / 256.0) / (total / columns as f32)
+}
+
+fn kasiski_spacings(cipher_text: &[u8]) -> Vec<usize> {
+    let mut last_seen = HashMap::new();
+    let mut output = vec![];
+
+    for (i, ngram) in cipher_text.windows(KASISKI_NGRAM_SIZE).enumerate() {
+        if let Some(previous) = last_seen.insert(ngram, i) {
+            output.push(i - previous);
+        }
     }
     output
 }
+
+// Random spacings are divisible by the key size 1 in keysize times, it's the
+// share of spacings beyond that which counts
+fn kasiski_score(spacings: &[usize], keysize: usize) -> f32 {
+    if spacings.is_empty() {
+        return 1.0;
+    }
+
+    let divisible = spacings
+        .iter()
+        .filter(|x| x.is_multiple_of(keysize))
+        .count();
+    let share = divisible as f32 / spacings.len() as f32;
+    1.0 - (share - 1.0 / keysize as f32)
+}
@@ -3,12 +3,13 @@ use std::io::BufRead;
 use std::io::BufReader;
 use std::path::Path;
 
-use crate::crackers::xor::single_byte::crack;
+use crate::crackers::xor::single_byte::crack_with;
+use crate::text_scoring::{Frequency, Scorer};
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use crate::Result;
 
 pub struct DetectEntryResult {
-    pub score: usize,
+    pub score: f64,
     pub key: u8,
     pub plain_text: Vec<u8>,
     pub index: usize,
@@ -18,17 +19,26 @@ pub fn detect_in_list<L, E>(entry_list: L) -> DetectEntryResult
 where
     L: AsRef<[E]>,
     E: AsRef<[u8]>,
+{
+    detect_in_list_with(&Frequency, entry_list)
+}
+
+pub fn detect_in_list_with<S, L, E>(scorer: &S, entry_list: L) -> DetectEntryResult
+where
+    S: Scorer + ?Sized,
+    L: AsRef<[E]>,
+    E: AsRef<[u8]>,
 {
     let entry_list = entry_list.as_ref();
     let mut output = DetectEntryResult {
-        score: 0,
+        score: f64::NEG_INFINITY,
         key: 0,
         plain_text: vec![],
         index: 0,
     };
 
     for (i, entry) in entry_list.iter().enumerate() {
-        let result = crack(entry);
+        let result = crack_with(scorer, entry);
         if result.score > output.score {
             output.score = result.score;
             output.key = result.key;
@@ -42,6 +52,14 @@ where
 pub fn detect_in_file<P>(path: P) -> Result<DetectEntryResult>
 where
     P: AsRef<Path>,
+{
+    detect_in_file_with(&Frequency, path)
+}
+
+pub fn detect_in_file_with<S, P>(scorer: &S, path: P) -> Result<DetectEntryResult>
+where
+    S: Scorer + ?Sized,
+    P: AsRef<Path>,
 {
     let file = BufReader::new(File::open(path)?);
 
@@ -49,5 +67,5 @@ where
     for line in file.lines() {
         data.push(hex::decode(line?)?);
     }
-    Ok(detect_in_list(data))
+    Ok(detect_in_list_with(scorer, data))
 }
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidKeyLength(usize),
+    InvalidIvLength(usize),
+    InvalidBlockSize(usize),
+    InvalidKey(&'static str),
+    InvalidPadding,
+    NotBlockMultiple(usize),
+    NotEnoughData(usize),
+    Io(io::Error),
+    Base64Decode(base64::DecodeError),
+    HexDecode(hex::FromHexError),
+    SearchExhausted(&'static str),
+    NotInvertible,
+    DuplicateSymbol(u8),
+    InvalidSymbol(u8),
+    InvalidDigestLength(usize),
+    ZeroModulus,
+    ZeroRoot,
+    InvalidBitSize(usize),
+    Protocol(&'static str),
+    CounterOverflow(u64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidKeyLength(i) => write!(f, "Invalid key length: {}", i),
+            Error::InvalidIvLength(i) => write!(f, "Invalid IV length: {}", i),
+            Error::InvalidBlockSize(i) => write!(f, "Invalid block size: {}", i),
+            Error::InvalidKey(e) => write!(f, "Invalid key: {}", e),
+            Error::InvalidPadding => write!(f, "Invalid padding"),
+            Error::NotBlockMultiple(i) => {
+                write!(f, "Data length {} isnt a multiple of the block size", i)
+            }
+            Error::NotEnoughData(i) => write!(f, "Not enough data: {}", i),
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Base64Decode(e) => write!(f, "Base64 decode error: {}", e),
+            Error::HexDecode(e) => write!(f, "Hex decode error: {}", e),
+            Error::SearchExhausted(e) => write!(f, "Search space exhausted: {}", e),
+            Error::NotInvertible => write!(f, "Number has no inverse for the modulus"),
+            Error::DuplicateSymbol(i) => write!(f, "Symbol {} appears twice", i),
+            Error::InvalidSymbol(i) => write!(f, "Invalid symbol: {}", i),
+            Error::InvalidDigestLength(i) => write!(f, "Invalid digest length: {}", i),
+            Error::ZeroModulus => write!(f, "Modulus is zero"),
+            Error::ZeroRoot => write!(f, "No zeroth root"),
+            Error::InvalidBitSize(i) => write!(f, "Invalid bit size: {}", i),
+            Error::Protocol(e) => write!(f, "Protocol error: {}", e),
+            Error::CounterOverflow(i) => write!(f, "Counter {} doesn't fit the layout", i),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Base64Decode(e) => Some(e),
+            Error::HexDecode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Error {
+        Error::Base64Decode(e)
+    }
+}
+
+impl From<hex::FromHexError> for Error {
+    fn from(e: hex::FromHexError) -> Error {
+        Error::HexDecode(e)
+    }
+}
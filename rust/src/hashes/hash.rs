@@ -0,0 +1,12 @@
+use crate::Result;
+
+// A Merkle–Damgård hash that can carry on from the state left in a digest
+pub trait ResumableHash: Sized {
+    const BLOCK_SIZE: usize;
+
+    fn new() -> Self;
+    fn from_digest(digest: &[u8], length: u64) -> Result<Self>;
+    fn padding(length: u64) -> Vec<u8>;
+    fn update(&mut self, data: &[u8]);
+    fn digest(&self) -> Vec<u8>;
+}
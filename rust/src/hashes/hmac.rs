@@ -0,0 +1,67 @@
+use crate::hashes::sha1::Sha1;
+use crate::hashes::ResumableHash;
+
+const INNER_PAD: u8 = 0x36;
+const OUTER_PAD: u8 = 0x5c;
+
+pub fn hmac<K, M>(key: K, message: M) -> Vec<u8>
+where
+    K: AsRef<[u8]>,
+    M: AsRef<[u8]>,
+{
+    hmac_with::<Sha1, _, _>(key, message)
+}
+
+// Source: https://tools.ietf.org/html/rfc2104
+pub fn hmac_with<H, K, M>(key: K, message: M) -> Vec<u8>
+where
+    H: ResumableHash,
+    K: AsRef<[u8]>,
+    M: AsRef<[u8]>,
+{
+    let key = key.as_ref();
+    let mut key = if key.len() > H::BLOCK_SIZE {
+        let mut hash = H::new();
+        hash.update(key);
+        hash.digest()
+    } else {
+        key.to_vec()
+    };
+    key.resize(H::BLOCK_SIZE, 0);
+
+    let mut inner = H::new();
+    inner.update(&key.iter().map(|x| x ^ INNER_PAD).collect::<Vec<u8>>());
+    inner.update(message.as_ref());
+
+    let mut outer = H::new();
+    outer.update(&key.iter().map(|x| x ^ OUTER_PAD).collect::<Vec<u8>>());
+    outer.update(&inner.digest());
+    outer.digest()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Source: https://tools.ietf.org/html/rfc2202#section-3
+    #[test]
+    fn test() {
+        let input: [(&[u8], &[u8]); 3] = [
+            (&[0x0b; 20], b"Hi There"),
+            (b"Jefe", b"what do ya want for nothing?"),
+            (
+                &[0xaa; 80],
+                b"Test Using Larger Than Block-Size Key - Hash Key First",
+            ),
+        ];
+        let expected = [
+            "b617318655057264e28bc0b6fb378c8ef146be00",
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+            "aa4ae5e15272d00e95705637ce8a3b55ed402112",
+        ];
+
+        for ((key, message), e) in input.iter().zip(expected.iter()) {
+            assert_eq!(hex::encode(hmac(key, message)), *e);
+        }
+    }
+}
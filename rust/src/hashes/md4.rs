@@ -0,0 +1,109 @@
+use crate::hashes::{Compression, MerkleDamgard};
+
+pub const DIGEST_SIZE: usize = 16;
+
+const INITIAL_STATE: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+// Message word order and rotations of each round
+const ROUND_2_WORDS: [usize; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+const ROUND_3_WORDS: [usize; 16] = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+const ROUND_1_SHIFTS: [u32; 4] = [3, 7, 11, 19];
+const ROUND_2_SHIFTS: [u32; 4] = [3, 5, 9, 13];
+const ROUND_3_SHIFTS: [u32; 4] = [3, 9, 11, 15];
+
+// Source: https://tools.ietf.org/html/rfc1320
+#[derive(Clone, Debug)]
+pub struct Md4Core;
+
+impl Compression for Md4Core {
+    type State = [u32; 4];
+
+    const INITIAL_STATE: [u32; 4] = INITIAL_STATE;
+    const BIG_ENDIAN: bool = false;
+
+    fn compress(state: &mut [u32; 4], block: &[u8]) {
+        compress(state, block)
+    }
+}
+
+pub type Md4 = MerkleDamgard<Md4Core>;
+
+pub fn digest<D>(data: D) -> [u8; DIGEST_SIZE]
+where
+    D: AsRef<[u8]>,
+{
+    let mut hash = Md4::new();
+    hash.update(data);
+
+    let mut output = [0; DIGEST_SIZE];
+    output.copy_from_slice(&hash.digest());
+    output
+}
+
+fn compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut x = [0u32; 16];
+    for (x, word) in x.iter_mut().zip(block.chunks_exact(4)) {
+        *x = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+    }
+
+    let mut registers = *state;
+    for i in 0..16 {
+        let f = |b: u32, c: u32, d: u32| (b & c) | (!b & d);
+        step(&mut registers, f, x[i], ROUND_1_SHIFTS[i % 4]);
+    }
+    for i in 0..16 {
+        let f = |b: u32, c: u32, d: u32| (b & c) | (b & d) | (c & d);
+        let word = x[ROUND_2_WORDS[i]].wrapping_add(0x5a82_7999);
+        step(&mut registers, f, word, ROUND_2_SHIFTS[i % 4]);
+    }
+    for i in 0..16 {
+        let f = |b: u32, c: u32, d: u32| b ^ c ^ d;
+        let word = x[ROUND_3_WORDS[i]].wrapping_add(0x6ed9_eba1);
+        step(&mut registers, f, word, ROUND_3_SHIFTS[i % 4]);
+    }
+
+    for (x, y) in state.iter_mut().zip(&registers) {
+        *x = x.wrapping_add(*y);
+    }
+}
+
+// Updates a, then rotates the registers so the next step updates d
+fn step<F>(registers: &mut [u32; 4], f: F, word: u32, shift: u32)
+where
+    F: Fn(u32, u32, u32) -> u32,
+{
+    let [a, b, c, d] = *registers;
+    let a = a
+        .wrapping_add(f(b, c, d))
+        .wrapping_add(word)
+        .rotate_left(shift);
+    *registers = [d, a, b, c];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Source: https://tools.ietf.org/html/rfc1320#appendix-A.5
+    #[test]
+    fn test() {
+        let input: [&[u8]; 5] = [
+            b"",
+            b"a",
+            b"abc",
+            b"message digest",
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        ];
+        let expected = [
+            "31d6cfe0d16ae931b73c59d7e0c089c0",
+            "bde52cb31de33e46245e05fbdbd6fb24",
+            "a448017aaf21d8525fc10ae87aa6729d",
+            "d9130a8164549fe818874806e1c7014b",
+            "e33b4ddc9c38f2199c3e7b164fcc0536",
+        ];
+
+        for (i, e) in input.iter().zip(expected.iter()) {
+            assert_eq!(hex::encode(digest(i)), *e);
+        }
+    }
+}
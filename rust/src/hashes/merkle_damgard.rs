@@ -0,0 +1,155 @@
+use crate::hashes::ResumableHash;
+use crate::Error;
+use crate::Result;
+use std::fmt::Debug;
+
+pub const BLOCK_SIZE: usize = 64;
+
+// What sets one Merkle–Damgård hash apart from another: the state it starts
+// from, the byte order of its words and its compression function
+pub trait Compression {
+    type State: Copy + Debug + AsRef<[u32]> + AsMut<[u32]>;
+
+    const INITIAL_STATE: Self::State;
+    const BIG_ENDIAN: bool;
+
+    fn compress(state: &mut Self::State, block: &[u8]);
+}
+
+// Buffers input into blocks for the compression function of `C`
+#[derive(Clone, Debug)]
+pub struct MerkleDamgard<C: Compression> {
+    state: C::State,
+    length: u64, // Bytes already hashed into the state
+    pending: Vec<u8>,
+}
+
+impl<C> MerkleDamgard<C>
+where
+    C: Compression,
+{
+    pub fn new() -> MerkleDamgard<C> {
+        MerkleDamgard::from_state(C::INITIAL_STATE, 0)
+    }
+
+    // Carries on from a state as if `length` bytes (padding included) had
+    // already been hashed
+    pub fn from_state(state: C::State, length: u64) -> MerkleDamgard<C> {
+        MerkleDamgard {
+            state,
+            length,
+            pending: Vec::with_capacity(BLOCK_SIZE),
+        }
+    }
+
+    // Same as from_state but with the state read back out of a digest
+    pub fn from_digest<D>(digest: D, length: u64) -> Result<MerkleDamgard<C>>
+    where
+        D: AsRef<[u8]>,
+    {
+        let digest = digest.as_ref();
+        let mut state = C::INITIAL_STATE;
+        if digest.len() != 4 * state.as_ref().len() {
+            return Err(Error::InvalidDigestLength(digest.len()));
+        }
+
+        for (x, word) in state.as_mut().iter_mut().zip(digest.chunks_exact(4)) {
+            let word = [word[0], word[1], word[2], word[3]];
+            *x = if C::BIG_ENDIAN {
+                u32::from_be_bytes(word)
+            } else {
+                u32::from_le_bytes(word)
+            };
+        }
+        Ok(MerkleDamgard::from_state(state, length))
+    }
+
+    // 0x80, zeros up to 8 bytes short of a block, then the length in bits
+    pub fn padding(length: u64) -> Vec<u8> {
+        let zeros = (BLOCK_SIZE - (length as usize + 9) % BLOCK_SIZE) % BLOCK_SIZE;
+        let length = length.wrapping_mul(8);
+
+        let mut output = Vec::with_capacity(zeros + 9);
+        output.push(0x80);
+        output.resize(zeros + 1, 0);
+        if C::BIG_ENDIAN {
+            output.extend(&length.to_be_bytes());
+        } else {
+            output.extend(&length.to_le_bytes());
+        }
+        output
+    }
+
+    pub fn update<D>(&mut self, data: D)
+    where
+        D: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            let size = (BLOCK_SIZE - self.pending.len()).min(data.len());
+            self.pending.extend(&data[..size]);
+            data = &data[size..];
+
+            if self.pending.len() == BLOCK_SIZE {
+                C::compress(&mut self.state, &self.pending);
+                self.length += BLOCK_SIZE as u64;
+                self.pending.clear();
+            }
+        }
+    }
+
+    pub fn digest(&self) -> Vec<u8> {
+        let mut state = self.state;
+        let mut last = self.pending.clone();
+        last.extend(Self::padding(self.length + self.pending.len() as u64));
+        for block in last.chunks_exact(BLOCK_SIZE) {
+            C::compress(&mut state, block);
+        }
+
+        let mut output = Vec::with_capacity(4 * state.as_ref().len());
+        for word in state.as_ref() {
+            if C::BIG_ENDIAN {
+                output.extend(&word.to_be_bytes());
+            } else {
+                output.extend(&word.to_le_bytes());
+            }
+        }
+        output
+    }
+}
+
+impl<C> Default for MerkleDamgard<C>
+where
+    C: Compression,
+{
+    fn default() -> MerkleDamgard<C> {
+        MerkleDamgard::new()
+    }
+}
+
+impl<C> ResumableHash for MerkleDamgard<C>
+where
+    C: Compression,
+{
+    const BLOCK_SIZE: usize = BLOCK_SIZE;
+
+    fn new() -> MerkleDamgard<C> {
+        MerkleDamgard::new()
+    }
+
+    fn from_digest(digest: &[u8], length: u64) -> Result<MerkleDamgard<C>> {
+        MerkleDamgard::from_digest(digest, length)
+    }
+
+    fn padding(length: u64) -> Vec<u8> {
+        MerkleDamgard::<C>::padding(length)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        MerkleDamgard::update(self, data)
+    }
+
+    fn digest(&self) -> Vec<u8> {
+        MerkleDamgard::digest(self)
+    }
+}
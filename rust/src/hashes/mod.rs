@@ -0,0 +1,9 @@
+mod hash;
+mod merkle_damgard;
+pub use hash::*;
+pub use merkle_damgard::*;
+
+pub mod hmac;
+pub mod md4;
+pub mod sha1;
+pub mod sha256;
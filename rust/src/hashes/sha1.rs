@@ -0,0 +1,121 @@
+use crate::hashes::{Compression, MerkleDamgard};
+
+pub const DIGEST_SIZE: usize = 20;
+
+const INITIAL_STATE: [u32; 5] = [
+    0x6745_2301,
+    0xefcd_ab89,
+    0x98ba_dcfe,
+    0x1032_5476,
+    0xc3d2_e1f0,
+];
+
+// Source: https://en.wikipedia.org/wiki/SHA-1
+#[derive(Clone, Debug)]
+pub struct Sha1Core;
+
+impl Compression for Sha1Core {
+    type State = [u32; 5];
+
+    const INITIAL_STATE: [u32; 5] = INITIAL_STATE;
+    const BIG_ENDIAN: bool = true;
+
+    fn compress(state: &mut [u32; 5], block: &[u8]) {
+        compress(state, block)
+    }
+}
+
+pub type Sha1 = MerkleDamgard<Sha1Core>;
+
+pub fn digest<D>(data: D) -> [u8; DIGEST_SIZE]
+where
+    D: AsRef<[u8]>,
+{
+    let mut hash = Sha1::new();
+    hash.update(data);
+
+    let mut output = [0; DIGEST_SIZE];
+    output.copy_from_slice(&hash.digest());
+    output
+}
+
+fn compress(state: &mut [u32; 5], block: &[u8]) {
+    let mut w = [0u32; 80];
+    for (x, word) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *x = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+    for (i, &x) in w.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => (d ^ (b & (c ^ d)), 0x5a82_7999),
+            20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+            40..=59 => ((b & c) | (d & (b | c)), 0x8f1b_bcdc),
+            _ => (b ^ c ^ d, 0xca62_c1d6),
+        };
+
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(x);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    for (x, y) in state.iter_mut().zip(&[a, b, c, d, e]) {
+        *x = x.wrapping_add(*y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::BLOCK_SIZE;
+
+    // Source: https://www.di-mgt.com.au/sha_testvectors.html
+    #[test]
+    fn test() {
+        let input: [&[u8]; 3] = [
+            b"",
+            b"abc",
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        ];
+        let expected = [
+            "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+            "a9993e364706816aba3e25717850c26c9cd0d89d",
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+        ];
+
+        for (i, e) in input.iter().zip(expected.iter()) {
+            assert_eq!(hex::encode(digest(i)), *e);
+        }
+    }
+
+    #[test]
+    fn million_a() {
+        let mut hash = Sha1::new();
+        for _ in 0..1000 {
+            hash.update([b'a'; 1000]);
+        }
+        assert_eq!(
+            hex::encode(hash.digest()),
+            "34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+        );
+    }
+
+    #[test]
+    fn padding_sizes() {
+        for length in 0..200 {
+            let size = length + Sha1::padding(length).len() as u64;
+            assert!(size.is_multiple_of(BLOCK_SIZE as u64));
+        }
+    }
+}
@@ -1,11 +1,19 @@
 #[macro_use]
 extern crate lazy_static;
 
+mod error;
+pub use error::*;
+
 pub mod utils;
 
 pub mod ciphers;
 pub mod crackers;
 pub mod detectors;
+pub mod hashes;
+pub mod math;
 
 pub mod oracles;
+pub mod prng;
+pub mod protocols;
+pub mod pubkey;
 pub mod text_scoring;
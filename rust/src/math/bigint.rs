@@ -0,0 +1,243 @@
+use num_bigint::{BigInt, RandBigInt, Sign};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+pub use num_bigint::BigUint;
+
+use crate::Error;
+use crate::Result;
+
+pub const MILLER_RABIN_ROUNDS: usize = 40;
+const SMALL_PRIMES_BELOW: u32 = 1000; // Trial divided before Miller-Rabin
+
+lazy_static! {
+    static ref SMALL_PRIMES: Vec<u32> = (2..SMALL_PRIMES_BELOW)
+        .filter(|x| (2..*x).take_while(|y| y * y <= *x).all(|y| x % y != 0))
+        .collect();
+}
+
+pub fn from_bytes_be<B>(bytes: B) -> BigUint
+where
+    B: AsRef<[u8]>,
+{
+    BigUint::from_bytes_be(bytes.as_ref())
+}
+
+pub fn from_bytes_le<B>(bytes: B) -> BigUint
+where
+    B: AsRef<[u8]>,
+{
+    BigUint::from_bytes_le(bytes.as_ref())
+}
+
+// Zero is a single 0 byte, same as num-bigint
+pub fn to_bytes_be(n: &BigUint) -> Vec<u8> {
+    n.to_bytes_be()
+}
+
+pub fn to_bytes_le(n: &BigUint) -> Vec<u8> {
+    n.to_bytes_le()
+}
+
+// Left padded with zeros up to `size` bytes, like an RSA block
+pub fn to_padded_bytes_be(n: &BigUint, size: usize) -> Vec<u8> {
+    let mut output = to_padded_bytes_le(n, size);
+    output.reverse();
+    output
+}
+
+pub fn to_padded_bytes_le(n: &BigUint, size: usize) -> Vec<u8> {
+    let mut output = n.to_bytes_le();
+    if output.len() < size {
+        output.resize(size, 0);
+    }
+    output
+}
+
+pub fn mod_pow(base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> Result<BigUint> {
+    if modulus.is_zero() {
+        return Err(Error::ZeroModulus);
+    }
+    Ok(base.modpow(exponent, modulus))
+}
+
+// Source: https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm#Modular_integers
+pub fn mod_inverse(a: &BigUint, modulus: &BigUint) -> Result<BigUint> {
+    if modulus.is_zero() {
+        return Err(Error::ZeroModulus);
+    }
+
+    let modulus = BigInt::from_biguint(Sign::Plus, modulus.clone());
+    let (mut t, mut new_t) = (BigInt::zero(), BigInt::one());
+    let (mut r, mut new_r) = (modulus.clone(), BigInt::from_biguint(Sign::Plus, a.clone()));
+
+    while !new_r.is_zero() {
+        let quotient = &r / &new_r;
+        let next_t = &t - &quotient * &new_t;
+        t = std::mem::replace(&mut new_t, next_t);
+        let next_r = &r - &quotient * &new_r;
+        r = std::mem::replace(&mut new_r, next_r);
+    }
+
+    if !r.is_one() {
+        return Err(Error::NotInvertible);
+    }
+    Ok(t.mod_floor(&modulus).to_biguint().unwrap())
+}
+
+// Rounded down
+pub fn nth_root(n: &BigUint, k: u32) -> Result<BigUint> {
+    if k == 0 {
+        return Err(Error::ZeroRoot);
+    }
+    Ok(n.nth_root(k))
+}
+
+// The smallest number with every (residue, modulus) pair's remainder, the
+// moduli needing to be pairwise coprime
+pub fn crt(pairs: &[(BigUint, BigUint)]) -> Result<BigUint> {
+    if pairs.iter().any(|(_, modulus)| modulus.is_zero()) {
+        return Err(Error::ZeroModulus);
+    }
+
+    let product: BigUint = pairs.iter().map(|(_, modulus)| modulus).product();
+
+    let mut output = BigUint::zero();
+    for (residue, modulus) in pairs {
+        let others = &product / modulus;
+        output += residue * &others * mod_inverse(&(&others % modulus), modulus)?;
+    }
+    Ok(output % product)
+}
+
+// Source: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+pub fn is_probable_prime(n: &BigUint, rounds: usize) -> bool {
+    for &prime in SMALL_PRIMES.iter() {
+        if *n == BigUint::from(prime) {
+            return true;
+        }
+        if (n % prime).is_zero() {
+            return false;
+        }
+    }
+    if *n < BigUint::from(SMALL_PRIMES_BELOW) {
+        return false; // 0 and 1
+    }
+
+    // n - 1 as d * 2^s with d odd
+    let n_1 = n - 1u32;
+    let s = n_1.trailing_zeros().unwrap();
+    let d = &n_1 >> s;
+
+    let mut rng = rand::thread_rng();
+    'rounds: for _ in 0..rounds {
+        let a = rng.gen_biguint_range(&BigUint::from(2u32), &n_1);
+        let mut x = a.modpow(&d, n);
+        if x.is_one() || x == n_1 {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_1 {
+                continue 'rounds;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// Odd and with the top two bits set, so multiplying two of them gives the
+// full 2 * bits, which takes at least 3 bits (Example arguments: 512)
+pub fn random_prime(bits: usize) -> Result<BigUint> {
+    if bits < 3 {
+        return Err(Error::InvalidBitSize(bits));
+    }
+
+    let mut rng = rand::thread_rng();
+    let top_bits = BigUint::from(3u32) << (bits - 2);
+    loop {
+        let n = rng.gen_biguint(bits as u64) | &top_bits | BigUint::one();
+        if is_probable_prime(&n, MILLER_RABIN_ROUNDS) {
+            return Ok(n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(x: u64) -> BigUint {
+        BigUint::from(x)
+    }
+
+    #[test]
+    fn bytes() {
+        let x = from_bytes_be([0x01, 0x02, 0x03]);
+        assert_eq!(x, n(0x010203));
+        assert_eq!(from_bytes_le([0x03, 0x02, 0x01]), x);
+        assert_eq!(to_bytes_le(&x), [0x03, 0x02, 0x01]);
+        assert_eq!(to_padded_bytes_be(&x, 5), [0, 0, 0x01, 0x02, 0x03]);
+        assert_eq!(to_padded_bytes_le(&x, 2), [0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(mod_inverse(&n(17), &n(3120)).unwrap(), n(2753));
+        assert_eq!(mod_inverse(&n(3), &n(11)).unwrap(), n(4));
+        assert!(matches!(
+            mod_inverse(&n(6), &n(9)),
+            Err(Error::NotInvertible)
+        ));
+        assert!(matches!(mod_inverse(&n(3), &n(0)), Err(Error::ZeroModulus)));
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(mod_pow(&n(4), &n(13), &n(497)).unwrap(), n(445));
+        assert!(matches!(
+            mod_pow(&n(4), &n(13), &n(0)),
+            Err(Error::ZeroModulus)
+        ));
+    }
+
+    #[test]
+    fn roots() {
+        let x = n(123_456_789);
+        assert_eq!(nth_root(&(&x * &x * &x), 3).unwrap(), x);
+        assert_eq!(nth_root(&(&x * &x * &x - 1u32), 3).unwrap(), &x - 1u32);
+        assert!(matches!(nth_root(&x, 0), Err(Error::ZeroRoot)));
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        let pairs = [(n(2), n(3)), (n(3), n(5)), (n(2), n(7))];
+        assert_eq!(crt(&pairs).unwrap(), n(23));
+        assert!(crt(&[(n(1), n(4)), (n(3), n(6))]).is_err());
+        assert!(matches!(
+            crt(&[(n(1), n(4)), (n(3), n(0))]),
+            Err(Error::ZeroModulus)
+        ));
+    }
+
+    #[test]
+    fn primes() {
+        assert!(is_probable_prime(&n(2), 10));
+        assert!(is_probable_prime(&n(7919), 10));
+        assert!(is_probable_prime(&n(2_305_843_009_213_693_951), 10)); // 2^61 - 1
+        assert!(!is_probable_prime(&n(1), 10));
+        assert!(!is_probable_prime(&n(561), 10)); // Carmichael
+        assert!(!is_probable_prime(
+            &(n(2_305_843_009_213_693_951) * n(1009)),
+            10
+        ));
+
+        let prime = random_prime(128).unwrap();
+        assert_eq!(prime.bits(), 128);
+        assert!(is_probable_prime(&prime, MILLER_RABIN_ROUNDS));
+        assert_eq!(random_prime(3).unwrap(), n(7));
+        assert!(matches!(random_prime(2), Err(Error::InvalidBitSize(2))));
+    }
+}
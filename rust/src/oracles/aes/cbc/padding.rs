@@ -0,0 +1,66 @@
+use aesni::block_cipher_trait::generic_array::GenericArray;
+use aesni::block_cipher_trait::BlockCipher;
+use aesni::Aes128;
+use rand::prelude::*;
+
+use crate::ciphers::aes::cbc::{decrypt_with_cipher, encrypt_with_cipher};
+use crate::crackers::aes::padding_oracle::PaddingOracle;
+use crate::Result;
+
+const BLOCK_SIZE: usize = 16;
+
+pub struct Oracle {
+    cipher: Aes128,
+    iv: [u8; BLOCK_SIZE],
+    cipher_text: Vec<u8>,
+}
+
+impl Oracle {
+    pub fn new<L, E>(plain_texts: L) -> Result<Oracle>
+    where
+        L: AsRef<[E]>,
+        E: AsRef<[u8]>,
+    {
+        let plain_texts = plain_texts.as_ref();
+
+        let mut rng = rand::thread_rng();
+        let key = rng.gen::<[u8; BLOCK_SIZE]>();
+        let iv = rng.gen::<[u8; BLOCK_SIZE]>();
+        let cipher = Aes128::new(GenericArray::from_slice(&key));
+
+        let mut cipher_text = match plain_texts.choose(&mut rng) {
+            Some(i) => i.as_ref().to_vec(),
+            None => vec![],
+        };
+        encrypt_with_cipher(&mut cipher_text, cipher, iv)?;
+
+        Ok(Oracle {
+            cipher,
+            iv,
+            cipher_text,
+        })
+    }
+
+    pub fn iv(&self) -> &[u8] {
+        &self.iv
+    }
+
+    pub fn cipher_text(&self) -> &[u8] {
+        &self.cipher_text
+    }
+
+    pub fn valid_padding<I, C>(&self, iv: I, cipher_text: C) -> bool
+    where
+        I: AsRef<[u8]>,
+        C: AsRef<[u8]>,
+    {
+        let mut data = cipher_text.as_ref().to_vec();
+        decrypt_with_cipher(&mut data, self.cipher, iv).is_ok()
+    }
+}
+
+impl PaddingOracle for Oracle {
+    fn valid_padding(&self, iv: &[u8], cipher_text: &[u8]) -> bool {
+        Oracle::valid_padding(self, iv, cipher_text)
+    }
+}
@@ -1,14 +1,16 @@
 use aesni::block_cipher_trait::generic_array::GenericArray;
 use aesni::block_cipher_trait::BlockCipher;
 use aesni::Aes128;
-use rand::random;
+use rand::prelude::*;
 
 use crate::ciphers::aes::ecb::encrypt_with_cipher;
 
 const BLOCK_SIZE: usize = 16;
+const HIDDEN_PREFIX_MAX: usize = 64;
 
 pub struct Oracle {
     cipher: Aes128,
+    prefix: Vec<u8>,
     suffix: Vec<u8>,
 }
 
@@ -22,17 +24,32 @@ impl Oracle {
         let key = random::<[u8; BLOCK_SIZE]>();
         Oracle {
             cipher: Aes128::new(GenericArray::from_slice(&key)),
+            prefix: vec![],
             suffix: suffix.to_vec(),
         }
     }
 
-    pub fn function<P>(&self, prefix: P) -> Vec<u8>
+    // Same as new() but also prepends a random length random prefix (Cryptopals 14)
+    pub fn new_with_hidden_prefix<S>(suffix: S) -> Oracle
+    where
+        S: AsRef<[u8]>,
+    {
+        let mut rng = rand::thread_rng();
+        let prefix_size = rng.gen_range(0, HIDDEN_PREFIX_MAX);
+
+        let mut output = Oracle::new(suffix);
+        output.prefix = (0..prefix_size).map(|_| rng.gen()).collect();
+        output
+    }
+
+    pub fn function<P>(&self, input: P) -> Vec<u8>
     where
         P: AsRef<[u8]>,
     {
-        let mut output = prefix.as_ref().to_vec();
+        let mut output = self.prefix.clone();
+        output.extend(input.as_ref());
         output.extend(&self.suffix);
-        encrypt_with_cipher(&mut output, self.cipher);
+        encrypt_with_cipher(&mut output, self.cipher).unwrap(); // AES blocks are 16 bytes
         output
     }
 }
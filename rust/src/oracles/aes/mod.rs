@@ -1,2 +1,3 @@
+pub mod cbc;
 pub mod ecb;
 pub mod random;
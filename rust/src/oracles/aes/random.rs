@@ -2,12 +2,13 @@ use rand::prelude::*;
 
 use crate::ciphers::aes::cbc::encrypt as cbc_encrypt;
 use crate::ciphers::aes::ecb::encrypt as ecb_encrypt;
+use crate::Result;
 
 const BLOCK_SIZE: usize = 16;
 const PLAY_WITH_MIN: usize = 5;
 const PLAY_WITH_MAX: usize = 11;
 
-pub fn aes_ecb<D>(data: D) -> Vec<u8>
+pub fn aes_ecb<D>(data: D) -> Result<Vec<u8>>
 where
     D: AsRef<[u8]>,
 {
@@ -17,11 +18,11 @@ where
     let key = rng.gen::<[u8; BLOCK_SIZE]>();
 
     let mut output = play_with_data(&mut rng, data);
-    ecb_encrypt(&mut output, key);
-    output
+    ecb_encrypt(&mut output, key)?;
+    Ok(output)
 }
 
-pub fn aes_cbc<D>(data: D) -> Vec<u8>
+pub fn aes_cbc<D>(data: D) -> Result<Vec<u8>>
 where
     D: AsRef<[u8]>,
 {
@@ -32,8 +33,8 @@ where
     let iv = rng.gen::<[u8; BLOCK_SIZE]>();
 
     let mut output = play_with_data(&mut rng, data);
-    cbc_encrypt(&mut output, key, iv);
-    output
+    cbc_encrypt(&mut output, key, iv)?;
+    Ok(output)
 }
 
 fn play_with_data(rng: &mut ThreadRng, data: &[u8]) -> Vec<u8> {
@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+
+use rand::prelude::*;
+
+use crate::crackers::hash::length_extension::MacOracle;
+use crate::hashes::ResumableHash;
+
+const KEY_MIN: usize = 16;
+const KEY_MAX: usize = 64;
+
+// Secret prefix MAC, H(key || message), under a random key of 16 to 64 bytes
+// (Cryptopals 28 to 30)
+pub struct Oracle<H> {
+    key: Vec<u8>,
+    hash: PhantomData<H>,
+}
+
+impl<H> Oracle<H>
+where
+    H: ResumableHash,
+{
+    pub fn new() -> Oracle<H> {
+        let mut rng = rand::thread_rng();
+        let key_size = rng.gen_range(KEY_MIN, KEY_MAX + 1);
+
+        Oracle {
+            key: (0..key_size).map(|_| rng.gen()).collect(),
+            hash: PhantomData,
+        }
+    }
+
+    pub fn mac<M>(&self, message: M) -> Vec<u8>
+    where
+        M: AsRef<[u8]>,
+    {
+        let mut hash = H::new();
+        hash.update(&self.key);
+        hash.update(message.as_ref());
+        hash.digest()
+    }
+
+    pub fn verify<M, A>(&self, message: M, mac: A) -> bool
+    where
+        M: AsRef<[u8]>,
+        A: AsRef<[u8]>,
+    {
+        self.mac(message) == mac.as_ref()
+    }
+}
+
+impl<H> Default for Oracle<H>
+where
+    H: ResumableHash,
+{
+    fn default() -> Oracle<H> {
+        Oracle::new()
+    }
+}
+
+impl<H> MacOracle for Oracle<H>
+where
+    H: ResumableHash,
+{
+    fn verify(&self, message: &[u8], mac: &[u8]) -> bool {
+        Oracle::verify(self, message, mac)
+    }
+}
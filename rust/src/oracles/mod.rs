@@ -1 +1,5 @@
 pub mod aes;
+pub mod mac;
+pub mod prng;
+pub mod rsa;
+pub mod timing;
@@ -0,0 +1 @@
+pub mod mt19937;
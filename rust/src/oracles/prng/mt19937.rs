@@ -0,0 +1,35 @@
+use rand::prelude::*;
+
+use crate::ciphers::mt19937::{crypt, keystream};
+use crate::prng::mt19937::Mt19937;
+use crate::utils::unix_timestamp;
+
+const WAIT_MIN: u32 = 40;
+const WAIT_MAX: u32 = 1000;
+const PREFIX_MIN: usize = 32;
+const PREFIX_MAX: usize = 96;
+const TOKEN_SIZE: usize = 32;
+
+// Pretends we waited a random 40 to 1000 seconds before seeding with the time (Cryptopals 22)
+pub fn timestamp_seeded_output() -> u32 {
+    let wait = rand::thread_rng().gen_range(WAIT_MIN, WAIT_MAX);
+    Mt19937::new(unix_timestamp() - wait).extract_number()
+}
+
+// Random prefix plus the suffix, encrypted under a random 16bit key (Cryptopals 24)
+pub fn stream_cipher<S>(suffix: S) -> Vec<u8>
+where
+    S: AsRef<[u8]>,
+{
+    let mut rng = rand::thread_rng();
+    let prefix_size = rng.gen_range(PREFIX_MIN, PREFIX_MAX);
+
+    let mut output: Vec<u8> = (0..prefix_size).map(|_| rng.gen()).collect();
+    output.extend(suffix.as_ref());
+    crypt(&mut output, rng.gen::<u16>() as u32);
+    output
+}
+
+pub fn password_reset_token() -> Vec<u8> {
+    keystream(unix_timestamp(), TOKEN_SIZE)
+}
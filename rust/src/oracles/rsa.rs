@@ -0,0 +1,41 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::crackers::rsa::unpadded_oracle::DecryptionOracle;
+use crate::math::bigint::BigUint;
+use crate::pubkey::rsa::{PrivateKey, PublicKey, DEFAULT_EXPONENT};
+use crate::Result;
+
+// A server decrypting unpadded RSA blobs, but each cipher text only the once
+// (Cryptopals 41)
+pub struct Oracle {
+    key: PrivateKey,
+    seen: RefCell<HashSet<BigUint>>,
+}
+
+impl Oracle {
+    // Example arguments: 1024
+    pub fn new(bits: usize) -> Result<Oracle> {
+        Ok(Oracle {
+            key: PrivateKey::new(bits, DEFAULT_EXPONENT)?,
+            seen: RefCell::new(HashSet::new()),
+        })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        self.key.public_key()
+    }
+
+    pub fn decrypt(&self, cipher_text: &BigUint) -> Option<BigUint> {
+        if !self.seen.borrow_mut().insert(cipher_text.clone()) {
+            return None;
+        }
+        Some(self.key.decrypt(cipher_text))
+    }
+}
+
+impl DecryptionOracle for Oracle {
+    fn decrypt(&self, cipher_text: &BigUint) -> Option<BigUint> {
+        Oracle::decrypt(self, cipher_text)
+    }
+}
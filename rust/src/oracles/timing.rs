@@ -0,0 +1,71 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use rand::prelude::*;
+
+use crate::crackers::hash::length_extension::MacOracle;
+use crate::hashes::hmac::hmac;
+use crate::hashes::sha1::DIGEST_SIZE;
+
+const KEY_MIN: usize = 32;
+const KEY_MAX: usize = 128;
+
+// Stands in for the web server checking HMAC-SHA1 signatures of files with an
+// early exit compare that sleeps after every matching byte (Cryptopals 31 & 32)
+pub struct Oracle {
+    key: Vec<u8>,
+    delay: Duration,
+    mac_size: usize,
+}
+
+impl Oracle {
+    pub fn new(delay: Duration) -> Oracle {
+        Oracle::with_mac_size(delay, DIGEST_SIZE)
+    }
+
+    // Only checks the first `mac_size` bytes of the HMAC, which keeps tests quick
+    pub fn with_mac_size(delay: Duration, mac_size: usize) -> Oracle {
+        let mut rng = rand::thread_rng();
+        let key_size = rng.gen_range(KEY_MIN, KEY_MAX + 1);
+
+        Oracle {
+            key: (0..key_size).map(|_| rng.gen()).collect(),
+            delay,
+            mac_size: mac_size.min(DIGEST_SIZE),
+        }
+    }
+
+    pub fn mac_size(&self) -> usize {
+        self.mac_size
+    }
+
+    pub fn verify<F, S>(&self, file: F, signature: S) -> bool
+    where
+        F: AsRef<[u8]>,
+        S: AsRef<[u8]>,
+    {
+        let mut mac = hmac(&self.key, file);
+        mac.truncate(self.mac_size);
+        insecure_compare(&mac, signature.as_ref(), self.delay)
+    }
+}
+
+impl MacOracle for Oracle {
+    fn verify(&self, message: &[u8], mac: &[u8]) -> bool {
+        Oracle::verify(self, message, mac)
+    }
+}
+
+fn insecure_compare(a: &[u8], b: &[u8], delay: Duration) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    for (x, y) in a.iter().zip(b) {
+        if x != y {
+            return false;
+        }
+        sleep(delay);
+    }
+    true
+}
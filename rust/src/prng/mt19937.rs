@@ -0,0 +1,266 @@
+use rand_core::{impls, Error, RngCore, SeedableRng};
+
+// Source: https://en.wikipedia.org/wiki/Mersenne_Twister
+//     & http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt.html
+macro_rules! mersenne_twister {
+    (
+        $name:ident, $temper:ident, $untemper:ident, $word:ty,
+        n: $n:expr, m: $m:expr, r: $r:expr, a: $a:expr,
+        u: $u:expr, d: $d:expr, s: $s:expr, b: $b:expr, t: $t:expr, c: $c:expr, l: $l:expr,
+        f: $f:expr, key_f1: $key_f1:expr, key_f2: $key_f2:expr, key_seed: $key_seed:expr,
+    ) => {
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            state: [$word; $n],
+            index: usize,
+        }
+
+        impl $name {
+            pub const STATE_SIZE: usize = $n;
+            pub const DEFAULT_SEED: $word = 5489;
+
+            pub fn new(seed: $word) -> $name {
+                let mut state = [0; $n];
+                state[0] = seed;
+                for i in 1..$n {
+                    let x = state[i - 1] ^ (state[i - 1] >> (<$word>::BITS - 2));
+                    state[i] = $f.wrapping_mul(x).wrapping_add(i as $word);
+                }
+                $name { state, index: $n }
+            }
+
+            // The reference implementation's init_by_array(), which needs at
+            // least one word of key
+            pub fn from_key<K>(key: K) -> crate::Result<$name>
+            where
+                K: AsRef<[$word]>,
+            {
+                let key = key.as_ref();
+                if key.is_empty() {
+                    return Err(crate::Error::NotEnoughData(0));
+                }
+
+                let mut output = $name::new($key_seed);
+                let state = &mut output.state;
+
+                let (mut i, mut j) = (1, 0);
+                for _ in 0..$n.max(key.len()) {
+                    let x = state[i - 1] ^ (state[i - 1] >> (<$word>::BITS - 2));
+                    state[i] = (state[i] ^ x.wrapping_mul($key_f1))
+                        .wrapping_add(key[j])
+                        .wrapping_add(j as $word);
+                    i += 1;
+                    j += 1;
+                    if i >= $n {
+                        state[0] = state[$n - 1];
+                        i = 1;
+                    }
+                    if j >= key.len() {
+                        j = 0;
+                    }
+                }
+                for _ in 0..$n - 1 {
+                    let x = state[i - 1] ^ (state[i - 1] >> (<$word>::BITS - 2));
+                    state[i] = (state[i] ^ x.wrapping_mul($key_f2)).wrapping_sub(i as $word);
+                    i += 1;
+                    if i >= $n {
+                        state[0] = state[$n - 1];
+                        i = 1;
+                    }
+                }
+                state[0] = 1 << (<$word>::BITS - 1);
+                Ok(output)
+            }
+
+            // Resumes from a raw (untempered) state as it was when its outputs were
+            // being extracted, so the next output twists it first
+            pub fn from_state(state: [$word; $n]) -> $name {
+                $name { state, index: $n }
+            }
+
+            pub fn extract_number(&mut self) -> $word {
+                if self.index >= $n {
+                    self.twist();
+                }
+
+                let output = $temper(self.state[self.index]);
+                self.index += 1;
+                output
+            }
+
+            fn twist(&mut self) {
+                let lower_mask: $word = (1 << $r) - 1;
+                let upper_mask: $word = !lower_mask;
+
+                for i in 0..$n {
+                    let x = (self.state[i] & upper_mask) | (self.state[(i + 1) % $n] & lower_mask);
+                    let mut x_a = x >> 1;
+                    if x & 1 != 0 {
+                        x_a ^= $a;
+                    }
+                    self.state[i] = self.state[(i + $m) % $n] ^ x_a;
+                }
+                self.index = 0;
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> $name {
+                $name::new($name::DEFAULT_SEED)
+            }
+        }
+
+        impl SeedableRng for $name {
+            type Seed = [u8; std::mem::size_of::<$word>()];
+
+            fn from_seed(seed: Self::Seed) -> $name {
+                $name::new(<$word>::from_le_bytes(seed))
+            }
+        }
+
+        pub fn $temper(mut y: $word) -> $word {
+            y ^= (y >> $u) & $d;
+            y ^= (y << $s) & $b;
+            y ^= (y << $t) & $c;
+            y ^= y >> $l;
+            y
+        }
+
+        // Each step is undone by re-applying it until every bit has fallen through
+        pub fn $untemper(y: $word) -> $word {
+            let undo_right = |y: $word, shift: u32, mask: $word| {
+                let mut x = y;
+                for _ in 0..<$word>::BITS / shift {
+                    x = y ^ ((x >> shift) & mask);
+                }
+                x
+            };
+            let undo_left = |y: $word, shift: u32, mask: $word| {
+                let mut x = y;
+                for _ in 0..<$word>::BITS / shift {
+                    x = y ^ ((x << shift) & mask);
+                }
+                x
+            };
+
+            let y = undo_right(y, $l, !0);
+            let y = undo_left(y, $t, $c);
+            let y = undo_left(y, $s, $b);
+            undo_right(y, $u, $d)
+        }
+    };
+}
+
+mersenne_twister!(
+    Mt19937, temper, untemper, u32,
+    n: 624, m: 397, r: 31, a: 0x9908_b0df,
+    u: 11, d: 0xffff_ffff, s: 7, b: 0x9d2c_5680, t: 15, c: 0xefc6_0000, l: 18,
+    f: 1_812_433_253u32, key_f1: 1_664_525, key_f2: 1_566_083_941, key_seed: 19_650_218,
+);
+
+mersenne_twister!(
+    Mt19937_64, temper_64, untemper_64, u64,
+    n: 312, m: 156, r: 31, a: 0xb502_6f5a_a966_19e9,
+    u: 29, d: 0x5555_5555_5555_5555, s: 17, b: 0x71d6_7fff_eda6_0000,
+    t: 37, c: 0xfff7_eee0_0000_0000, l: 43,
+    f: 6_364_136_223_846_793_005u64, key_f1: 3_935_559_000_370_003_845,
+    key_f2: 2_862_933_555_777_941_757, key_seed: 19_650_218,
+);
+
+impl RngCore for Mt19937 {
+    fn next_u32(&mut self) -> u32 {
+        self.extract_number()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl RngCore for Mt19937_64 {
+    fn next_u32(&mut self) -> u32 {
+        self.extract_number() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.extract_number()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Source: http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/MT2002/CODES/mt19937ar.out
+    #[test]
+    fn test() {
+        let expected = [
+            1067595299, 955945823, 477289528, 4107218783, 4228976476, 3344332714, 3355579695,
+            227628506, 810200273, 2591290167,
+        ];
+
+        let mut rng = Mt19937::from_key([0x123, 0x234, 0x345, 0x456]).unwrap();
+        for i in expected.iter() {
+            assert_eq!(rng.next_u32(), *i);
+        }
+    }
+
+    // Source: http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/mt64.html (mt19937-64.out.txt)
+    #[test]
+    fn test_64() {
+        let expected = [
+            7266447313870364031,
+            4946485549665804864,
+            16945909448695747420,
+            16394063075524226720,
+            4873882236456199058,
+        ];
+
+        let mut rng = Mt19937_64::from_key([0x12345, 0x23456, 0x34567, 0x45678]).unwrap();
+        for i in expected.iter() {
+            assert_eq!(rng.next_u64(), *i);
+        }
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(Mt19937::from_key([]).is_err());
+        assert!(Mt19937_64::from_key([]).is_err());
+    }
+
+    #[test]
+    fn default_seed() {
+        assert_eq!(Mt19937::default().next_u32(), 3499211612);
+        assert_eq!(Mt19937_64::default().next_u64(), 14514284786278117030);
+    }
+
+    #[test]
+    fn untempering() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let x = rng.next_u32();
+            assert_eq!(untemper(temper(x)), x);
+
+            let x = rng.next_u64();
+            assert_eq!(untemper_64(temper_64(x)), x);
+        }
+    }
+}
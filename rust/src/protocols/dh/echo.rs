@@ -0,0 +1,158 @@
+use super::exchange::{decrypt_message, encrypt_message, KeyPair, Parameters, KEY_SIZE};
+use crate::math::bigint::BigUint;
+use crate::Error;
+use crate::Result;
+
+// Alice picks the group and Bob acknowledges it before they swap public
+// keys, then Bob echoes back every message Alice sends (Cryptopals 34 & 35)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    Parameters(Parameters),
+    Ack,
+    PublicKey(BigUint),
+    Message(Vec<u8>), // See exchange::encrypt_message
+}
+
+pub trait Middleman {
+    fn alice_to_bob(&mut self, packet: Packet) -> Result<Packet>;
+    fn bob_to_alice(&mut self, packet: Packet) -> Result<Packet>;
+}
+
+// Passes every packet along untouched
+pub struct Relay;
+
+impl Middleman for Relay {
+    fn alice_to_bob(&mut self, packet: Packet) -> Result<Packet> {
+        Ok(packet)
+    }
+
+    fn bob_to_alice(&mut self, packet: Packet) -> Result<Packet> {
+        Ok(packet)
+    }
+}
+
+pub struct Alice {
+    parameters: Parameters,
+    messages: Vec<Vec<u8>>,
+    sent: usize,
+    key_pair: Option<KeyPair>,
+    session_key: Option<[u8; KEY_SIZE]>,
+    pub received: Vec<Vec<u8>>,
+}
+
+impl Alice {
+    pub fn new<L, E>(parameters: Parameters, messages: L) -> Alice
+    where
+        L: AsRef<[E]>,
+        E: AsRef<[u8]>,
+    {
+        Alice {
+            parameters,
+            messages: messages
+                .as_ref()
+                .iter()
+                .map(|x| x.as_ref().to_vec())
+                .collect(),
+            sent: 0,
+            key_pair: None,
+            session_key: None,
+            received: vec![],
+        }
+    }
+
+    pub fn start(&mut self) -> Packet {
+        self.key_pair = Some(KeyPair::new(self.parameters.clone()));
+        Packet::Parameters(self.parameters.clone())
+    }
+
+    // None once every message has been echoed back
+    pub fn receive(&mut self, packet: Packet) -> Result<Option<Packet>> {
+        let key_pair = self
+            .key_pair
+            .as_ref()
+            .ok_or(Error::Protocol("Alice hasn't started"))?;
+
+        match packet {
+            Packet::Ack => Ok(Some(Packet::PublicKey(key_pair.public_key().clone()))),
+            Packet::PublicKey(x) => {
+                self.session_key = Some(key_pair.session_key(&x));
+                self.next_message()
+            }
+            Packet::Message(x) => {
+                let key = self.session_key.ok_or(Error::Protocol("No session key"))?;
+                self.received.push(decrypt_message(key, x)?);
+                self.next_message()
+            }
+            Packet::Parameters(_) => Err(Error::Protocol("Alice picks the parameters")),
+        }
+    }
+
+    fn next_message(&mut self) -> Result<Option<Packet>> {
+        let key = self.session_key.ok_or(Error::Protocol("No session key"))?;
+        let message = match self.messages.get(self.sent) {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+
+        self.sent += 1;
+        Ok(Some(Packet::Message(encrypt_message(key, message)?)))
+    }
+}
+
+#[derive(Default)]
+pub struct Bob {
+    key_pair: Option<KeyPair>,
+    session_key: Option<[u8; KEY_SIZE]>,
+    pub received: Vec<Vec<u8>>,
+}
+
+impl Bob {
+    pub fn new() -> Bob {
+        Bob::default()
+    }
+
+    pub fn receive(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::Parameters(x) => {
+                x.check()?;
+                self.key_pair = Some(KeyPair::new(x));
+                Ok(Packet::Ack)
+            }
+            Packet::PublicKey(x) => {
+                let key_pair = self
+                    .key_pair
+                    .as_ref()
+                    .ok_or(Error::Protocol("No parameters"))?;
+                self.session_key = Some(key_pair.session_key(&x));
+                Ok(Packet::PublicKey(key_pair.public_key().clone()))
+            }
+            Packet::Message(x) => {
+                let key = self.session_key.ok_or(Error::Protocol("No session key"))?;
+                let message = decrypt_message(key, x)?;
+                let output = encrypt_message(key, &message)?;
+                self.received.push(message);
+                Ok(Packet::Message(output))
+            }
+            Packet::Ack => Err(Error::Protocol("Bob sends the acks")),
+        }
+    }
+}
+
+pub fn run(alice: &mut Alice, bob: &mut Bob) -> Result<()> {
+    run_with(&mut Relay, alice, bob)
+}
+
+// Carries packets between Alice and Bob through the middleman until Alice
+// has nothing left to say
+pub fn run_with<M>(middleman: &mut M, alice: &mut Alice, bob: &mut Bob) -> Result<()>
+where
+    M: Middleman + ?Sized,
+{
+    let mut request = Some(alice.start());
+    while let Some(packet) = request {
+        let packet = middleman.alice_to_bob(packet)?;
+        let response = middleman.bob_to_alice(bob.receive(packet)?)?;
+        request = alice.receive(response)?;
+    }
+    Ok(())
+}
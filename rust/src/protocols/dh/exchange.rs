@@ -0,0 +1,133 @@
+use num_bigint::RandBigInt;
+
+use crate::ciphers::aes::cbc::{decrypt, encrypt};
+use crate::hashes::sha1::digest;
+use crate::math::bigint::{to_bytes_be, BigUint};
+use crate::Error;
+use crate::Result;
+
+pub const KEY_SIZE: usize = 16;
+const IV_SIZE: usize = 16;
+
+// Source: https://tools.ietf.org/html/rfc3526#section-2
+const NIST_P: &str = "\
+    ffffffffffffffffc90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b139b22514a08798e3404\
+    ddef9519b3cd3a431b302b0a6df25f14374fe1356d6d51c245e485b576625e7ec6f44c42e9a637ed6b0bff5cb6f406\
+    b7edee386bfb5a899fa5ae9f24117c4b1fe649286651ece45b3dc2007cb8a163bf0598da48361c55d39a69163fa8fd\
+    24cf5f83655d23dca3ad961c62f356208552bb9ed529077096966d670c354e4abc9804f1746c08ca237327ffffffff\
+    ffffffff";
+const NIST_G: u32 = 2;
+
+lazy_static! {
+    static ref NIST: Parameters = Parameters {
+        p: BigUint::parse_bytes(NIST_P.as_bytes(), 16).unwrap(),
+        g: BigUint::from(NIST_G),
+    };
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameters {
+    pub p: BigUint,
+    pub g: BigUint,
+}
+
+impl Parameters {
+    pub fn new(p: BigUint, g: BigUint) -> Parameters {
+        Parameters { p, g }
+    }
+
+    // The 1536-bit MODP group Cryptopals 33 uses
+    pub fn nist() -> Parameters {
+        NIST.clone()
+    }
+
+    // For parameters from the other side. There's no arithmetic mod 0, but a
+    // g of 1, p or p - 1 still gets through for Cryptopals 35
+    pub fn check(&self) -> Result<()> {
+        if self.p < BigUint::from(2u32) {
+            return Err(Error::Protocol("Modulus below 2"));
+        }
+        if self.g > self.p {
+            return Err(Error::Protocol("Generator above the modulus"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Parameters {
+        Parameters::nist()
+    }
+}
+
+pub struct KeyPair {
+    parameters: Parameters,
+    private_key: BigUint,
+    public_key: BigUint,
+}
+
+impl KeyPair {
+    pub fn new(parameters: Parameters) -> KeyPair {
+        let private_key = rand::thread_rng().gen_biguint_below(&parameters.p);
+        let public_key = parameters.g.modpow(&private_key, &parameters.p);
+
+        KeyPair {
+            parameters,
+            private_key,
+            public_key,
+        }
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    pub fn public_key(&self) -> &BigUint {
+        &self.public_key
+    }
+
+    pub fn shared_secret(&self, public_key: &BigUint) -> BigUint {
+        public_key.modpow(&self.private_key, &self.parameters.p)
+    }
+
+    pub fn session_key(&self, public_key: &BigUint) -> [u8; KEY_SIZE] {
+        session_key(&self.shared_secret(public_key))
+    }
+}
+
+// The first 16 bytes of SHA1 of the big endian secret
+pub fn session_key(secret: &BigUint) -> [u8; KEY_SIZE] {
+    let mut output = [0; KEY_SIZE];
+    output.copy_from_slice(&digest(to_bytes_be(secret))[..KEY_SIZE]);
+    output
+}
+
+// AES-CBC under a random IV, which is sent after the cipher text
+pub fn encrypt_message<K, M>(key: K, message: M) -> Result<Vec<u8>>
+where
+    K: AsRef<[u8]>,
+    M: AsRef<[u8]>,
+{
+    let iv = rand::random::<[u8; IV_SIZE]>();
+
+    let mut output = message.as_ref().to_vec();
+    encrypt(&mut output, key, iv)?;
+    output.extend(&iv);
+    Ok(output)
+}
+
+pub fn decrypt_message<K, D>(key: K, data: D) -> Result<Vec<u8>>
+where
+    K: AsRef<[u8]>,
+    D: AsRef<[u8]>,
+{
+    let data = data.as_ref();
+    if data.len() < IV_SIZE {
+        return Err(Error::NotEnoughData(data.len()));
+    }
+
+    let (cipher_text, iv) = data.split_at(data.len() - IV_SIZE);
+    let mut output = cipher_text.to_vec();
+    decrypt(&mut output, key, iv)?;
+    Ok(output)
+}
@@ -0,0 +1,2 @@
+pub mod echo;
+pub mod exchange;
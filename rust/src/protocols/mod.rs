@@ -0,0 +1,2 @@
+pub mod dh;
+pub mod srp;
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use rand::prelude::*;
+
+use crate::hashes::hmac::hmac_with;
+use crate::hashes::sha256::{digest, Sha256};
+use crate::math::bigint::{from_bytes_be, to_bytes_be, to_padded_bytes_be, BigUint};
+use crate::protocols::dh::exchange::Parameters as DhParameters;
+
+const SALT_SIZE: usize = 16;
+const SRP6_K: u32 = 3;
+
+// Source: https://en.wikipedia.org/wiki/Secure_Remote_Password_protocol
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameters {
+    pub n: BigUint,
+    pub g: BigUint,
+    pub k: BigUint,
+}
+
+impl Parameters {
+    pub fn new(n: BigUint, g: BigUint, k: BigUint) -> Parameters {
+        Parameters { n, g, k }
+    }
+
+    // The Diffie-Hellman NIST prime with g = 2, for SRP-6a
+    pub fn nist() -> Parameters {
+        let dh = DhParameters::nist();
+        let k = multiplier(&dh.p, &dh.g);
+        Parameters::new(dh.p, dh.g, k)
+    }
+
+    // Same group for the original SRP-6 with k = 3 (Cryptopals 36)
+    pub fn nist_srp6() -> Parameters {
+        let dh = DhParameters::nist();
+        Parameters::new(dh.p, dh.g, BigUint::from(SRP6_K))
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Parameters {
+        Parameters::nist()
+    }
+}
+
+pub struct Verifier {
+    pub salt: Vec<u8>,
+    pub verifier: BigUint, // g^x
+}
+
+// What the server keeps instead of passwords
+pub struct Verifiers {
+    parameters: Parameters,
+    users: HashMap<String, Verifier>,
+}
+
+impl Verifiers {
+    pub fn new(parameters: Parameters) -> Verifiers {
+        Verifiers {
+            parameters,
+            users: HashMap::new(),
+        }
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    // Replaces any verifier the user already had, under a fresh salt
+    pub fn add<U, P>(&mut self, username: U, password: P)
+    where
+        U: AsRef<str>,
+        P: AsRef<[u8]>,
+    {
+        let salt = thread_rng().gen::<[u8; SALT_SIZE]>().to_vec();
+        let x = private_key(&salt, password);
+        let verifier = self.parameters.g.modpow(&x, &self.parameters.n);
+
+        self.users
+            .insert(username.as_ref().to_string(), Verifier { salt, verifier });
+    }
+
+    pub fn get<U>(&self, username: U) -> Option<&Verifier>
+    where
+        U: AsRef<str>,
+    {
+        self.users.get(username.as_ref())
+    }
+}
+
+// Source: https://tools.ietf.org/html/rfc5054#section-2.5.3
+// k = SHA256(N || g), g left padded to the size of N as SRP-6a has it
+pub fn multiplier(n: &BigUint, g: &BigUint) -> BigUint {
+    let mut data = to_bytes_be(n);
+    data.extend(to_padded_bytes_be(g, data.len()));
+    from_bytes_be(digest(data))
+}
+
+// x = SHA256(salt || password)
+pub fn private_key<S, P>(salt: S, password: P) -> BigUint
+where
+    S: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    let mut data = salt.as_ref().to_vec();
+    data.extend(password.as_ref());
+    from_bytes_be(digest(data))
+}
+
+// u = SHA256(A || B)
+pub fn scrambler(client_public_key: &BigUint, server_public_key: &BigUint) -> BigUint {
+    let mut data = to_bytes_be(client_public_key);
+    data.extend(to_bytes_be(server_public_key));
+    from_bytes_be(digest(data))
+}
+
+// K = SHA256(S)
+pub fn session_key(secret: &BigUint) -> Vec<u8> {
+    digest(to_bytes_be(secret)).to_vec()
+}
+
+// HMAC-SHA256(K, salt), what the client sends to show it has K
+pub fn proof<K, S>(session_key: K, salt: S) -> Vec<u8>
+where
+    K: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    hmac_with::<Sha256, _, _>(session_key, salt)
+}
@@ -0,0 +1,207 @@
+use num_bigint::RandBigInt;
+use rand::prelude::*;
+
+use super::exchange::{private_key, proof, scrambler, session_key, Parameters, Verifiers};
+use crate::math::bigint::BigUint;
+use crate::Error;
+use crate::Result;
+
+const SIMPLE_SCRAMBLER_BITS: u64 = 128;
+
+// The client says who it is, the server answers with the user's salt and its
+// public key, then the client proves it derived the same session key
+// (Cryptopals 36 to 38)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    Hello {
+        username: String,
+        public_key: BigUint,
+    },
+    Challenge {
+        salt: Vec<u8>,
+        public_key: BigUint,
+    },
+    // Simplified SRP, B = g^b without the verifier and a random u
+    SimpleChallenge {
+        salt: Vec<u8>,
+        public_key: BigUint,
+        u: BigUint,
+    },
+    Proof(Vec<u8>),
+    Ok,
+    Denied,
+}
+
+// Anything a client can log in to, real or not
+pub trait Responder {
+    fn respond(&mut self, packet: Packet) -> Result<Packet>;
+}
+
+pub struct Client {
+    parameters: Parameters,
+    username: String,
+    password: Vec<u8>,
+    private_key: BigUint,
+    public_key: BigUint,
+    logged_in: bool,
+}
+
+impl Client {
+    pub fn new<U, P>(parameters: Parameters, username: U, password: P) -> Client
+    where
+        U: AsRef<str>,
+        P: AsRef<[u8]>,
+    {
+        let private_key = thread_rng().gen_biguint_below(&parameters.n);
+        let public_key = parameters.g.modpow(&private_key, &parameters.n);
+
+        Client {
+            parameters,
+            username: username.as_ref().to_string(),
+            password: password.as_ref().to_vec(),
+            private_key,
+            public_key,
+            logged_in: false,
+        }
+    }
+
+    pub fn logged_in(&self) -> bool {
+        self.logged_in
+    }
+
+    pub fn start(&mut self) -> Packet {
+        Packet::Hello {
+            username: self.username.clone(),
+            public_key: self.public_key.clone(),
+        }
+    }
+
+    // None once the server has made up its mind
+    pub fn receive(&mut self, packet: Packet) -> Result<Option<Packet>> {
+        let Parameters { n, g, k } = &self.parameters;
+
+        let (salt, secret) = match packet {
+            // S = (B - k * g^x)^(a + u * x)
+            Packet::Challenge { salt, public_key } => {
+                let x = private_key(&salt, &self.password);
+                let u = scrambler(&self.public_key, &public_key);
+                let base = (public_key + n * k - k * g.modpow(&x, n) % n) % n;
+                (salt, base.modpow(&(&self.private_key + u * x), n))
+            }
+            // S = B^(a + u * x)
+            Packet::SimpleChallenge {
+                salt,
+                public_key,
+                u,
+            } => {
+                let x = private_key(&salt, &self.password);
+                (salt, public_key.modpow(&(&self.private_key + u * x), n))
+            }
+            Packet::Ok => {
+                self.logged_in = true;
+                return Ok(None);
+            }
+            Packet::Denied => return Ok(None),
+            _ => return Err(Error::Protocol("Clients don't get sent that")),
+        };
+
+        Ok(Some(Packet::Proof(proof(session_key(&secret), salt))))
+    }
+}
+
+pub struct Server {
+    verifiers: Verifiers,
+    simple: bool,
+    expected_proof: Option<Vec<u8>>,
+}
+
+impl Server {
+    pub fn new(verifiers: Verifiers) -> Server {
+        Server {
+            verifiers,
+            simple: false,
+            expected_proof: None,
+        }
+    }
+
+    // Simplified SRP (Cryptopals 38)
+    pub fn simple(verifiers: Verifiers) -> Server {
+        Server {
+            simple: true,
+            ..Server::new(verifiers)
+        }
+    }
+
+    // Nothing checks A mod N isn't 0, which Cryptopals 37 takes advantage of
+    pub fn respond(&mut self, packet: Packet) -> Result<Packet> {
+        match packet {
+            Packet::Hello {
+                username,
+                public_key,
+            } => Ok(self.challenge(&username, &public_key)),
+            Packet::Proof(x) => {
+                let valid = self.expected_proof.take() == Some(x);
+                Ok(if valid { Packet::Ok } else { Packet::Denied })
+            }
+            _ => Err(Error::Protocol("Servers don't get sent that")),
+        }
+    }
+
+    fn challenge(&mut self, username: &str, client_public_key: &BigUint) -> Packet {
+        // A proof is only good for the challenge it answers
+        self.expected_proof = None;
+
+        let Parameters { n, g, k } = self.verifiers.parameters();
+        let user = match self.verifiers.get(username) {
+            Some(x) => x,
+            None => return Packet::Denied,
+        };
+
+        let mut rng = thread_rng();
+        let private_key = rng.gen_biguint_below(n);
+        let (public_key, u) = if self.simple {
+            let public_key = g.modpow(&private_key, n);
+            let u = rng.gen_biguint(SIMPLE_SCRAMBLER_BITS);
+            (public_key, u)
+        } else {
+            let public_key = (k * &user.verifier + g.modpow(&private_key, n)) % n;
+            let u = scrambler(client_public_key, &public_key);
+            (public_key, u)
+        };
+
+        // S = (A * v^u)^b
+        let base = client_public_key * user.verifier.modpow(&u, n) % n;
+        let secret = base.modpow(&private_key, n);
+        self.expected_proof = Some(proof(session_key(&secret), &user.salt));
+
+        let salt = user.salt.clone();
+        if self.simple {
+            Packet::SimpleChallenge {
+                salt,
+                public_key,
+                u,
+            }
+        } else {
+            Packet::Challenge { salt, public_key }
+        }
+    }
+}
+
+impl Responder for Server {
+    fn respond(&mut self, packet: Packet) -> Result<Packet> {
+        Server::respond(self, packet)
+    }
+}
+
+// Passes packets between the client and server until the client is done,
+// returning whether it got logged in
+pub fn run<R>(client: &mut Client, server: &mut R) -> Result<bool>
+where
+    R: Responder + ?Sized,
+{
+    let mut request = Some(client.start());
+    while let Some(packet) = request {
+        request = client.receive(server.respond(packet)?)?;
+    }
+    Ok(client.logged_in())
+}
@@ -0,0 +1,2 @@
+pub mod exchange;
+pub mod login;
@@ -0,0 +1,148 @@
+use crate::math::bigint::{mod_inverse, random_prime, BigUint};
+use crate::Error;
+use crate::Result;
+
+pub const DEFAULT_EXPONENT: u32 = 65537;
+const MAX_PRIME_DRAWS: usize = 100; // Small moduli can have too few primes to pick from
+
+// Textbook RSA, no padding so the same message always encrypts the same way
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    pub n: BigUint,
+    pub e: BigUint,
+}
+
+impl PublicKey {
+    pub fn new(n: BigUint, e: BigUint) -> PublicKey {
+        PublicKey { n, e }
+    }
+
+    pub fn encrypt(&self, message: &BigUint) -> BigUint {
+        message.modpow(&self.e, &self.n)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrivateKey {
+    public_key: PublicKey,
+    d: BigUint,
+    p: BigUint,
+    q: BigUint,
+    dp: BigUint,    // d mod (p - 1)
+    dq: BigUint,    // d mod (q - 1)
+    q_inv: BigUint, // q^-1 mod p
+}
+
+impl PrivateKey {
+    // Example arguments: 1024, 3
+    // A modulus of exactly `bits` bits, primes where e has no inverse are
+    // thrown away and drawn again. An even e never has one, as p - 1 is even
+    pub fn new(bits: usize, e: u32) -> Result<PrivateKey> {
+        if bits < 6 {
+            return Err(Error::InvalidBitSize(bits));
+        }
+        if e.is_multiple_of(2) {
+            return Err(Error::NotInvertible);
+        }
+
+        for _ in 0..MAX_PRIME_DRAWS {
+            let p = random_prime(bits - bits / 2)?;
+            let q = random_prime(bits / 2)?;
+            if let Ok(x) = PrivateKey::from_primes(p, q, BigUint::from(e)) {
+                return Ok(x);
+            }
+        }
+        Err(Error::SearchExhausted(
+            "No primes that e has an inverse for",
+        ))
+    }
+
+    // Errors when e has no inverse mod (p - 1)(q - 1), or p and q are the same
+    // so q has none mod p
+    pub fn from_primes(p: BigUint, q: BigUint, e: BigUint) -> Result<PrivateKey> {
+        let p_1 = &p - 1u32;
+        let q_1 = &q - 1u32;
+        let d = mod_inverse(&e, &(&p_1 * &q_1))?;
+
+        Ok(PrivateKey {
+            public_key: PublicKey::new(&p * &q, e),
+            dp: &d % &p_1,
+            dq: &d % &q_1,
+            q_inv: mod_inverse(&q, &p)?,
+            d,
+            p,
+            q,
+        })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn d(&self) -> &BigUint {
+        &self.d
+    }
+
+    pub fn encrypt(&self, message: &BigUint) -> BigUint {
+        self.public_key.encrypt(message)
+    }
+
+    // Source: https://en.wikipedia.org/wiki/RSA_(cryptosystem)#Using_the_Chinese_remainder_algorithm
+    // c^d mod n from two half size exponentiations, mod p and mod q
+    pub fn decrypt(&self, cipher_text: &BigUint) -> BigUint {
+        let m1 = cipher_text.modpow(&self.dp, &self.p);
+        let m2 = cipher_text.modpow(&self.dq, &self.q);
+        let h = &self.q_inv * (&m1 + &self.p - &m2 % &self.p) % &self.p;
+        m2 + h * &self.q
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(x: u64) -> BigUint {
+        BigUint::from(x)
+    }
+
+    // Source: https://en.wikipedia.org/wiki/RSA_(cryptosystem)#Example
+    #[test]
+    fn small_key() {
+        let key = PrivateKey::from_primes(n(61), n(53), n(17)).unwrap();
+        assert_eq!(key.public_key().n, n(3233));
+        assert_eq!(*key.d(), n(2753));
+        assert_eq!(key.encrypt(&n(65)), n(2790));
+        assert_eq!(key.decrypt(&n(2790)), n(65));
+    }
+
+    #[test]
+    fn same_primes() {
+        assert!(PrivateKey::from_primes(n(61), n(61), n(7)).is_err());
+    }
+
+    #[test]
+    fn bad_parameters() {
+        assert!(matches!(PrivateKey::new(512, 0), Err(Error::NotInvertible)));
+        assert!(matches!(PrivateKey::new(512, 2), Err(Error::NotInvertible)));
+        assert!(matches!(
+            PrivateKey::new(5, 3),
+            Err(Error::InvalidBitSize(5))
+        ));
+        // Both primes are always 7
+        assert!(matches!(
+            PrivateKey::new(6, 3),
+            Err(Error::SearchExhausted(_))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = PrivateKey::new(256, 3).unwrap();
+        assert_eq!(key.public_key().n.bits(), 256);
+
+        let message = n(0x1234_5678_9abc_def0);
+        let cipher_text = key.encrypt(&message);
+        assert_eq!(key.decrypt(&cipher_text), message);
+        assert_eq!(cipher_text.modpow(key.d(), &key.public_key().n), message);
+    }
+}
@@ -0,0 +1,19 @@
+use super::ngram::Table;
+
+// Every bigram counted from the same text as the quadgram table
+const TABLE: &str = include_str!("bigrams.txt");
+const TABLE_TOTAL: f64 = 18_773_866.0;
+const UNSEEN_COUNT: f64 = 1.0;
+
+lazy_static! {
+    static ref BIGRAMS: Table = Table::new(TABLE, 2, TABLE_TOTAL, UNSEEN_COUNT);
+}
+
+// Average log10 probability of each bigram in the letters of the data,
+// ignoring case and everything that isn't a letter. Higher is better
+pub fn score<D>(data: D) -> f64
+where
+    D: AsRef<[u8]>,
+{
+    BIGRAMS.score(data.as_ref())
+}
@@ -0,0 +1,59 @@
+// Source: https://en.wikipedia.org/wiki/Letter_frequency
+const LETTERS: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
+    0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
+    0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
+];
+
+// Rough share of each class of byte in English text, adds up to 1
+const LOWER_CASE: f64 = 0.74;
+const UPPER_CASE: f64 = 0.04;
+const SPACE: f64 = 0.17;
+const DIGIT: f64 = 0.005 / 10.0;
+const PUNCTUATION: f64 = 0.045 / 35.0; // 32 symbols plus \t \n \r
+const NON_PRINTABLE: f64 = 1e-9;
+
+fn probability(byte: u8) -> f64 {
+    match byte {
+        b'a'..=b'z' => LETTERS[(byte - b'a') as usize] * LOWER_CASE,
+        b'A'..=b'Z' => LETTERS[(byte - b'A') as usize] * UPPER_CASE,
+        b' ' => SPACE,
+        b'0'..=b'9' => DIGIT,
+        b'!'..=b'~' | b'\t' | b'\n' | b'\r' => PUNCTUATION,
+        _ => NON_PRINTABLE,
+    }
+}
+
+// Average log-likelihood per byte of the data being English text, higher is
+// better and scores of different lengths can be compared
+pub fn score<D>(data: D) -> f64
+where
+    D: AsRef<[u8]>,
+{
+    let data = data.as_ref();
+    if data.is_empty() {
+        return f64::NEG_INFINITY;
+    }
+
+    let total: f64 = data.iter().map(|&x| probability(x).ln()).sum();
+    total / data.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let english = score("Cooking MC's like a pound of bacon");
+        let shouting = score("COOKING MC'S LIKE A POUND OF BACON");
+        let symbols = score("#%&*(@!)$%^&*{}[]|;:<>,./?~`+=-_'\"");
+        let binary = score([0, 1, 2, 3, 0x80, 0x90, 0xff]);
+
+        assert!(english > shouting);
+        assert!(shouting > symbols);
+        assert!(symbols > binary);
+        assert!(score("Short") > score("Sh0r7"));
+        assert_eq!(score([]), f64::NEG_INFINITY);
+    }
+}
@@ -1 +1,9 @@
+mod ngram;
+mod scorer;
+pub use scorer::*;
+
+pub mod bigram;
 pub mod common;
+pub mod frequency;
+pub mod printable;
+pub mod quadgram;
@@ -0,0 +1,50 @@
+const ALPHABET_SIZE: usize = 26;
+
+// Log10 probabilities of every letter n-gram, from a table of "NGRAM count"
+// lines. N-grams missing from the table count as `unseen_count`
+pub struct Table {
+    size: usize,
+    log_probabilities: Vec<f64>,
+}
+
+impl Table {
+    pub fn new(table: &str, size: usize, total: f64, unseen_count: f64) -> Table {
+        let mut log_probabilities =
+            vec![(unseen_count / total).log10(); ALPHABET_SIZE.pow(size as u32)];
+        for line in table.lines() {
+            let (ngram, count) = line.split_at(size);
+            let count: f64 = count.trim().parse().unwrap();
+            log_probabilities[index(ngram.as_bytes())] = (count / total).log10();
+        }
+
+        Table {
+            size,
+            log_probabilities,
+        }
+    }
+
+    // Average log10 probability of each n-gram in the letters of the data,
+    // ignoring case and everything that isn't a letter. Higher is better
+    pub fn score(&self, data: &[u8]) -> f64 {
+        let letters: Vec<u8> = data
+            .iter()
+            .filter(|x| x.is_ascii_alphabetic())
+            .map(|x| x.to_ascii_uppercase())
+            .collect();
+        if letters.len() < self.size {
+            return f64::NEG_INFINITY;
+        }
+
+        let total: f64 = letters
+            .windows(self.size)
+            .map(|x| self.log_probabilities[index(x)])
+            .sum();
+        total / (letters.len() - self.size + 1) as f64
+    }
+}
+
+fn index(letters: &[u8]) -> usize {
+    letters
+        .iter()
+        .fold(0, |a, x| a * ALPHABET_SIZE + (x - b'A') as usize)
+}
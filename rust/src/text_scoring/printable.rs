@@ -0,0 +1,16 @@
+// Share of the data that is printable ASCII, from 0 to 1
+pub fn ratio<D>(data: D) -> f64
+where
+    D: AsRef<[u8]>,
+{
+    let data = data.as_ref();
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    let count = data
+        .iter()
+        .filter(|x| matches!(x, b' '..=b'~' | b'\t' | b'\n' | b'\r'))
+        .count();
+    count as f64 / data.len() as f64
+}
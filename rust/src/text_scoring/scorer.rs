@@ -0,0 +1,51 @@
+use super::{bigram, common, frequency, printable, quadgram};
+
+// Higher is better, only compared against other scores from the same scorer
+pub trait Scorer {
+    fn score(&self, data: &[u8]) -> f64;
+}
+
+impl<F> Scorer for F
+where
+    F: Fn(&[u8]) -> f64,
+{
+    fn score(&self, data: &[u8]) -> f64 {
+        self(data)
+    }
+}
+
+pub struct CommonChars;
+pub struct Printable;
+pub struct Frequency;
+pub struct Quadgrams;
+pub struct Bigrams;
+
+impl Scorer for CommonChars {
+    fn score(&self, data: &[u8]) -> f64 {
+        common::count(data) as f64
+    }
+}
+
+impl Scorer for Printable {
+    fn score(&self, data: &[u8]) -> f64 {
+        printable::ratio(data)
+    }
+}
+
+impl Scorer for Frequency {
+    fn score(&self, data: &[u8]) -> f64 {
+        frequency::score(data)
+    }
+}
+
+impl Scorer for Quadgrams {
+    fn score(&self, data: &[u8]) -> f64 {
+        quadgram::score(data)
+    }
+}
+
+impl Scorer for Bigrams {
+    fn score(&self, data: &[u8]) -> f64 {
+        bigram::score(data)
+    }
+}
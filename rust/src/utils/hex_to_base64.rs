@@ -1,8 +1,9 @@
 use base64::encode as b64_encode;
 use hex::decode as hex_decode;
-use hex::FromHexError;
 
-pub fn hex_to_base64<H>(hex: H) -> Result<String, FromHexError>
+use crate::Result;
+
+pub fn hex_to_base64<H>(hex: H) -> Result<String>
 where
     H: AsRef<[u8]>,
 {
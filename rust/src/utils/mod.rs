@@ -9,3 +9,6 @@ pub use hamming_distance::*;
 
 mod pad;
 pub use pad::*;
+
+mod unix_timestamp;
+pub use unix_timestamp::*;
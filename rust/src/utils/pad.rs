@@ -1,3 +1,6 @@
+use crate::Error;
+use crate::Result;
+
 pub fn pad(data: &mut Vec<u8>, block_size: u8) {
     let pad: u8 = block_size - (data.len() % (block_size as usize)) as u8;
     data.reserve(pad as usize);
@@ -6,13 +9,13 @@ pub fn pad(data: &mut Vec<u8>, block_size: u8) {
     }
 }
 
-pub fn unpad(data: &mut Vec<u8>, block_size: u8) -> bool {
+pub fn unpad(data: &mut Vec<u8>, block_size: u8) -> Result<()> {
     let pad = match data.last() {
         Some(i) => *i,
-        None => return false, // Data is empty, nothing to pad
+        None => return Err(Error::InvalidPadding), // Data is empty, nothing to pad
     };
-    if pad > block_size {
-        return false; // Last bytes value greater than block_size (invalid padding)
+    if pad == 0 || pad > block_size {
+        return Err(Error::InvalidPadding); // Last bytes value is zero or greater than block_size (invalid padding)
     }
 
     let mut iter = data.iter().rev().skip(1); // Skip the byte we already checked just above
@@ -20,13 +23,29 @@ pub fn unpad(data: &mut Vec<u8>, block_size: u8) -> bool {
         match iter.next() {
             Some(i) => {
                 if *i != pad {
-                    return false; // Byte isnt the same value as the last byte (invalid padding)
+                    return Err(Error::InvalidPadding); // Byte isnt the same value as the last byte (invalid padding)
                 }
             }
-            None => return false, // Data not long enough for the padding it has (invalid padding)
+            None => return Err(Error::InvalidPadding), // Data not long enough for the padding it has (invalid padding)
         }
     }
     let new_size = data.len() - (pad as usize);
     data.truncate(new_size);
-    true
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut data = b"ICE ICE BABY\x04\x04\x04\x04".to_vec();
+        assert!(unpad(&mut data, 16).is_ok());
+        assert_eq!(data, b"ICE ICE BABY");
+
+        assert!(unpad(&mut b"ICE ICE BABY\x05\x05\x05\x05".to_vec(), 16).is_err());
+        assert!(unpad(&mut b"ICE ICE BABY\x01\x02\x03\x04".to_vec(), 16).is_err());
+        assert!(unpad(&mut b"ICE ICE BABY\x00".to_vec(), 16).is_err());
+    }
 }
@@ -1,7 +1,7 @@
 use hex::decode as hex_decode;
 use hex::encode as hex_encode;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use crate::Result;
 
 pub fn xor<L, R>(mut left: L, right: R)
 where
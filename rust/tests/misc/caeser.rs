@@ -51,3 +51,13 @@ fn cipher_left() {
     crypt(&mut input, input_key, input_shift);
     assert_eq!(input, expected);
 }
+
+#[test]
+fn cipher_keys_past_alphabet() {
+    let mut input = b"ABCD".to_vec();
+
+    crypt(&mut input, ALPHABET_SIZE + 1, Shift::Right);
+    assert_eq!(input, b"BCDE");
+    crypt(&mut input, u8::MAX, Shift::Left);
+    assert_eq!(input, b"GHIJ");
+}
@@ -0,0 +1,120 @@
+extern crate cryptopals;
+
+use cryptopals::ciphers::classical::*;
+use cryptopals::crackers::classical::{crack_affine, crack_shift};
+use cryptopals::Error;
+
+// Source: https://en.wikipedia.org/wiki/Affine_cipher
+#[test]
+fn affine() {
+    let mut input = b"AFFINE CIPHER".to_vec();
+    let expected = b"IHHWVC SWFRCP";
+
+    affine_encrypt(&mut input, 5, 8, &Alphabet::letters()).unwrap();
+    assert_eq!(input, expected);
+
+    affine_decrypt(&mut input, 5, 8, &Alphabet::letters()).unwrap();
+    assert_eq!(input, b"AFFINE CIPHER");
+}
+
+#[test]
+fn affine_not_invertible() {
+    let mut input = b"AFFINE CIPHER".to_vec();
+
+    let result = affine_encrypt(&mut input, 13, 8, &Alphabet::letters());
+    assert!(matches!(result, Err(Error::NotInvertible)));
+    assert!(matches!(mod_inverse(13, 26), Err(Error::NotInvertible)));
+    assert!(matches!(mod_inverse(5, 0), Err(Error::ZeroModulus)));
+    assert!(matches!(
+        Alphabet::new("ABCA"),
+        Err(Error::DuplicateSymbol(b'A'))
+    ));
+}
+
+#[test]
+fn keys_larger_than_alphabet() {
+    let letters = Alphabet::letters();
+    let mut input = b"AFFINE CIPHER".to_vec();
+    let mut expected = input.clone();
+
+    shift(&mut input, usize::MAX, &letters);
+    shift(&mut expected, usize::MAX % 26, &letters);
+    assert_eq!(input, expected);
+    unshift(&mut input, usize::MAX, &letters);
+    assert_eq!(input, b"AFFINE CIPHER");
+
+    let mut expected = input.clone();
+    affine_encrypt(&mut input, 26 * 1000 + 5, usize::MAX, &letters).unwrap();
+    affine_encrypt(&mut expected, 5, usize::MAX % 26, &letters).unwrap();
+    assert_eq!(input, expected);
+    affine_decrypt(&mut input, 26 * 1000 + 5, usize::MAX, &letters).unwrap();
+    assert_eq!(input, b"AFFINE CIPHER");
+}
+
+#[test]
+fn single_symbol_alphabet() {
+    let alphabets = [Alphabet::new("X").unwrap()];
+    let mut input = b"XYX".to_vec();
+
+    assert_eq!(mod_inverse(4, 1).unwrap(), 0);
+    affine_encrypt(&mut input, 4, 3, &alphabets).unwrap();
+    assert_eq!(input, b"XYX");
+    affine_decrypt(&mut input, 4, 3, &alphabets).unwrap();
+    assert_eq!(input, b"XYX");
+}
+
+// Source: https://en.wikipedia.org/wiki/Atbash
+#[test]
+fn atbash_letters() {
+    let mut input = b"Attack at dawn".to_vec();
+    let expected = b"Zggzxp zg wzdm";
+
+    atbash(&mut input, &Alphabet::letters());
+    assert_eq!(input, expected);
+}
+
+// Source: https://en.wikipedia.org/wiki/ROT13#Variants
+#[test]
+fn rot47_printable() {
+    let mut input = b"The Quick Brown Fox Jumps Over The Lazy Dog.".to_vec();
+    let expected = br#"%96 "F:4< qC@H? u@I yF>AD ~G6C %96 {2KJ s@8]"#;
+
+    rot47(&mut input);
+    assert_eq!(input, &expected[..]);
+
+    rot47(&mut input);
+    assert_eq!(input, b"The Quick Brown Fox Jumps Over The Lazy Dog.");
+}
+
+#[test]
+fn other_alphabets() {
+    let mut input = b"PIN 2024, ref Zz+/".to_vec();
+
+    shift(&mut input, 3, &[Alphabet::digits()]);
+    assert_eq!(input, b"PIN 5357, ref Zz+/");
+
+    shift(&mut input, 2, &[Alphabet::base64()]);
+    assert_eq!(input, b"RKP 7579, tgh b1AB");
+}
+
+#[test]
+fn crack_affine_letters() {
+    let input = "Jffg jf kp gaf xcy xdv gsff dmgfs gaf sdhq adz zgxeefy mdcchqt";
+    let expected = b"Meet me by the old oak tree after the rain has stopped falling";
+
+    let result = crack_affine(input, &Alphabet::letters());
+
+    assert_eq!((result.a, result.b), (7, 3));
+    assert_eq!(result.plain_text, expected);
+}
+
+#[test]
+fn crack_rot47() {
+    let input = br#"%96 "F:4< qC@H? u@I yF>AD ~G6C %96 {2KJ s@8]"#;
+    let expected = b"The Quick Brown Fox Jumps Over The Lazy Dog.";
+
+    let result = crack_shift(&input[..], &[Alphabet::printable()]);
+
+    assert_eq!(result.b, 47);
+    assert_eq!(result.plain_text, expected);
+}
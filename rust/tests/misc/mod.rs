@@ -1 +1,7 @@
 mod caeser;
+mod classical;
+mod scorers;
+mod substitution;
+mod transposition;
+mod vigenere;
+mod xor;
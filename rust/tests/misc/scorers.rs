@@ -0,0 +1,45 @@
+extern crate cryptopals;
+
+use cryptopals::ciphers::xor::single_byte::crypt;
+use cryptopals::crackers::xor::single_byte::crack_with;
+use cryptopals::text_scoring::{CommonChars, Printable, Scorer};
+
+#[test]
+fn common_chars() {
+    let input = "1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736";
+    let expected = b"Cooking MC's like a pound of bacon";
+
+    let result = crack_with(&CommonChars, hex::decode(input).unwrap());
+
+    assert_eq!(result.plain_text, expected);
+}
+
+#[test]
+fn png_header() {
+    let input_key = 0xa7;
+    let expected = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\x01\0\0\0\x01\0\x08\x06\0\0\0";
+
+    let mut cipher_text = expected.to_vec();
+    crypt(&mut cipher_text, input_key);
+    let result = crack_with(
+        &|x: &[u8]| x.starts_with(b"\x89PNG") as u8 as f64,
+        cipher_text,
+    );
+
+    assert_eq!(result.key, input_key);
+    assert_eq!(result.plain_text, expected);
+}
+
+#[test]
+fn json() {
+    let input_key = 0x5c;
+    let expected = br#"{"id":1,"tags":["a","b"],"ok":true}"#;
+
+    let mut cipher_text = expected.to_vec();
+    crypt(&mut cipher_text, input_key);
+    let json = |x: &[u8]| Printable.score(x) + x.starts_with(b"{\"") as u8 as f64;
+    let result = crack_with(&json, cipher_text);
+
+    assert_eq!(result.key, input_key);
+    assert_eq!(result.plain_text, expected);
+}
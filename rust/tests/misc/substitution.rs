@@ -0,0 +1,65 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::ciphers::substitution::{decrypt, encrypt, invert_key, IDENTITY_KEY};
+use cryptopals::crackers::substitution::crack;
+use cryptopals::Error;
+
+// Source: https://en.wikipedia.org/wiki/Substitution_cipher
+#[test]
+fn cipher() {
+    let mut input = b"flee at once. we are discovered!".to_vec();
+    let input_key = "ZEBRASCDFGHIJKLMNOPQTUVWXY";
+    let expected = b"siaa zq lkba. va zoa rfpbluaoar!";
+
+    encrypt(&mut input, input_key).unwrap();
+    assert_eq!(input, expected);
+
+    decrypt(&mut input, input_key).unwrap();
+    assert_eq!(input, b"flee at once. we are discovered!");
+}
+
+#[test]
+fn invalid_keys() {
+    let mut input = b"flee at once".to_vec();
+    assert!(matches!(
+        encrypt(&mut input, "ZEBRA"),
+        Err(Error::InvalidKeyLength(5))
+    ));
+    assert!(matches!(
+        decrypt(&mut input, "ZEBRASCDFGHIJKLMNOPQTUVWX!"),
+        Err(Error::InvalidSymbol(b'!'))
+    ));
+    assert!(matches!(
+        invert_key("ZEBRASCDFGHIJKLMNOPQTUVWXZ"),
+        Err(Error::DuplicateSymbol(b'Z'))
+    ));
+    assert_eq!(input, b"flee at once");
+}
+
+#[test]
+fn crack_moby_dick() {
+    let expected = "Call me Ishmael. Some years ago, never mind how long precisely, having little \
+        or no money in my purse, and nothing particular to interest me on shore, I thought I \
+        would sail about a little and see the watery part of the world. It is a way I have of \
+        driving off the spleen and regulating the circulation. Whenever I find myself growing \
+        grim about the mouth; whenever it is a damp, drizzly November in my soul; then, I \
+        account it high time to get to sea as soon as I can.";
+    let mut input_key = IDENTITY_KEY;
+    input_key.shuffle(&mut thread_rng());
+
+    let mut cipher_text = expected.as_bytes().to_vec();
+    encrypt(&mut cipher_text, input_key).unwrap();
+
+    let result = crack(cipher_text, 5);
+
+    // A rare letter like the z in drizzly can trade places with one that never shows up
+    let wrong = result
+        .plain_text
+        .iter()
+        .zip(expected.bytes())
+        .filter(|(&a, b)| a != *b)
+        .count();
+    assert!(wrong <= 4);
+}
@@ -0,0 +1,92 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::ciphers::transposition::*;
+use cryptopals::crackers::transposition::{crack_columnar, crack_rail_fence};
+use cryptopals::Error;
+
+const PLAIN_TEXT: &str = "Call me Ishmael. Some years ago, never mind how long precisely, having \
+    little or no money in my purse, and nothing particular to interest me on shore, I thought \
+    I would sail about a little and see the watery part of the world.";
+
+// Source: https://en.wikipedia.org/wiki/Transposition_cipher#Columnar_transposition
+#[test]
+fn columnar() {
+    let mut input = b"WEAREDISCOVEREDFLEEATONCE".to_vec();
+    let input_keyword = "ZEBRAS";
+    let expected = b"EVLNACDTESEAROFODEECWIREE";
+
+    columnar_encrypt(&mut input, input_keyword).unwrap();
+    assert_eq!(input, expected);
+
+    columnar_decrypt(&mut input, input_keyword).unwrap();
+    assert_eq!(input, b"WEAREDISCOVEREDFLEEATONCE");
+}
+
+// Source: https://en.wikipedia.org/wiki/Rail_fence_cipher
+#[test]
+fn rail_fence() {
+    let mut input = b"WEAREDISCOVEREDRUNATONCE".to_vec();
+    let expected = b"WECRUOERDSOEERNTNEAIVDAC";
+
+    rail_fence_encrypt(&mut input, 3).unwrap();
+    assert_eq!(input, expected);
+
+    rail_fence_decrypt(&mut input, 3).unwrap();
+    assert_eq!(input, b"WEAREDISCOVEREDRUNATONCE");
+}
+
+#[test]
+fn invalid_keys() {
+    let mut input = b"WEAREDISCOVERED".to_vec();
+    assert!(matches!(
+        columnar_encrypt(&mut input, ""),
+        Err(Error::InvalidKeyLength(0))
+    ));
+    assert!(matches!(
+        columnar_decrypt_with_order(&mut input, &[0, 2, 2]),
+        Err(Error::InvalidKey(_))
+    ));
+    assert!(matches!(
+        rail_fence_encrypt(&mut input, 0),
+        Err(Error::InvalidKey(_))
+    ));
+    assert_eq!(input, b"WEAREDISCOVERED");
+}
+
+#[test]
+fn crack_rail_fence_sentence() {
+    let mut cipher_text = PLAIN_TEXT.as_bytes().to_vec();
+    rail_fence_encrypt(&mut cipher_text, 5).unwrap();
+
+    let result = crack_rail_fence(cipher_text, 10);
+
+    assert_eq!(result.rails, 5);
+    assert_eq!(result.plain_text, PLAIN_TEXT.as_bytes());
+}
+
+#[test]
+fn crack_columnar_exhaustive() {
+    let mut cipher_text = PLAIN_TEXT.as_bytes().to_vec();
+    columnar_encrypt(&mut cipher_text, "WHALE").unwrap();
+
+    let result = crack_columnar(cipher_text, 7, 1);
+
+    assert_eq!(result.order, column_order("WHALE"));
+    assert_eq!(result.plain_text, PLAIN_TEXT.as_bytes());
+}
+
+#[test]
+fn crack_columnar_climbing() {
+    let mut input_order: Vec<usize> = (0..10).collect();
+    input_order.shuffle(&mut thread_rng());
+
+    let mut cipher_text = PLAIN_TEXT.as_bytes().to_vec();
+    columnar_encrypt_with_order(&mut cipher_text, &input_order).unwrap();
+
+    let result = crack_columnar(cipher_text, 10, 10);
+
+    assert_eq!(result.order, input_order);
+    assert_eq!(result.plain_text, PLAIN_TEXT.as_bytes());
+}
@@ -0,0 +1,62 @@
+extern crate cryptopals;
+
+use cryptopals::ciphers::caesar::Shift;
+use cryptopals::ciphers::vigenere::crypt;
+use cryptopals::crackers::vigenere::crack;
+use cryptopals::Error;
+
+// Source: https://en.wikipedia.org/wiki/Vigen%C3%A8re_cipher
+#[test]
+fn cipher() {
+    let mut input = b"ATTACKATDAWN".to_vec();
+    let input_key = "LEMON";
+    let expected = b"LXFOPVEFRNHR";
+
+    crypt(&mut input, input_key, Shift::Right).unwrap();
+    assert_eq!(input, expected);
+
+    crypt(&mut input, input_key, Shift::Left).unwrap();
+    assert_eq!(input, b"ATTACKATDAWN");
+}
+
+#[test]
+fn cipher_skips_non_letters() {
+    let mut input = b"Attack at dawn!".to_vec();
+    let input_key = "lemon";
+    let expected = b"Lxfopv ef rnhr!";
+
+    crypt(&mut input, input_key, Shift::Right).unwrap();
+    assert_eq!(input, expected);
+}
+
+#[test]
+fn invalid_keys() {
+    let mut input = b"ATTACKATDAWN".to_vec();
+    assert!(matches!(
+        crypt(&mut input, "", Shift::Right),
+        Err(Error::InvalidKeyLength(0))
+    ));
+    assert!(matches!(
+        crypt(&mut input, "LEM0N", Shift::Right),
+        Err(Error::InvalidSymbol(b'0'))
+    ));
+    assert_eq!(input, b"ATTACKATDAWN");
+}
+
+#[test]
+fn crack_dickens() {
+    let input = "Lb ykw gzh jgcx bx wqoow, vl ziu dlr orzud ss llugc, mg oda vri nyh wh gmfvru, \
+        kd ank wpg kkr gi nqypvkkvgcw, vl ziu dlr wswer ss thtkoj, vl ziu dlr wswer ss \
+        aqktohhdlba, sx jsv bjo wrsvwp yj Yajpv, sx jsv bjo wrsvwp yj Qsuspowf, aw ecc xuw \
+        vxtsrt gi pqzi, vl ziu dlr olvvov bx gmuzevj, zm jkh rnhzadlvfj jgpsew xa, yo lnv \
+        qwvrmay emhyvr mv, eg giew dtn qsvfj lkbipl ww Joeiwq, eg giew dtn qsvfj lkbipl wpg \
+        yxuwu eci.";
+    let expected_key = b"DICKENS";
+
+    let result = crack(input, 20);
+
+    assert_eq!(result.key, expected_key);
+    assert!(result
+        .plain_text
+        .starts_with(b"It was the best of times, it was the worst of times"));
+}
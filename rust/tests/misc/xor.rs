@@ -0,0 +1,80 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::ciphers::xor::repeating_bytes::crypt;
+use cryptopals::crackers::xor::crib::{drag_pair, recover_key};
+use cryptopals::crackers::xor::repeating_bytes::{crack, crack_runner_ups_with};
+use cryptopals::crackers::xor::single_byte::crack_ranked;
+use cryptopals::utils::xor;
+
+#[test]
+fn single_byte_ranked() {
+    let input = "1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736";
+
+    let result = crack_ranked(hex::decode(input).unwrap(), 5);
+
+    assert_eq!(result.len(), 5);
+    assert_eq!(result[0].key, b'X');
+    assert!(result.windows(2).all(|x| x[0].score >= x[1].score));
+}
+
+// Too little text for the column statistics to get every key byte right,
+// a scorer that looks across columns can pick out the right runner up
+#[test]
+fn repeating_bytes_runner_ups() {
+    let input_key = b"LEMONADE";
+    let expected = b"It was a bright cold day in April, and the clocks were striking \
+        thirteen. Winston Smith, his chin nuzzled into his breast in an effort to escape \
+        the vile wind, slipped quickly through the glass doors";
+
+    let mut cipher_text = expected.to_vec();
+    crypt(&mut cipher_text, input_key);
+
+    let bigrams = |x: &[u8]| {
+        let common = [
+            b"th", b"he", b"in", b"er", b"an", b"re", b"on", b"at", b"en", b"nd",
+        ];
+        x.windows(2)
+            .filter(|x| common.iter().any(|y| y[..] == x[..]))
+            .count() as f64
+    };
+    let result = crack(&cipher_text, 2, 16, 20, 3);
+    let result = crack_runner_ups_with(&bigrams, &cipher_text, &result, 4);
+
+    crypt(&mut cipher_text, &result.key);
+    assert_eq!(cipher_text, &expected[..]);
+}
+
+#[test]
+fn crib_repeating_key() {
+    let input_key = b"ICE";
+    let expected = b"Burning 'em, if you ain't quick and nimble\nI go crazy when I hear a cymbal";
+
+    let mut cipher_text = expected.to_vec();
+    crypt(&mut cipher_text, input_key);
+
+    let result = recover_key(&cipher_text, " quick and ");
+
+    assert_eq!(result[0].key, input_key);
+    assert_eq!(result[0].period, 3);
+    assert_eq!(result[0].offsets, [25]);
+    assert_eq!(result[0].plain_text, &expected[..]);
+}
+
+#[test]
+fn crib_two_time_pad() {
+    let keystream = random::<[u8; 32]>();
+    let input_left = b"Attack the east gate at dawn";
+    let input_right = b"Retreat to the river, hold it";
+
+    let mut left = input_left.to_vec();
+    let mut right = input_right.to_vec();
+    xor(&mut left, keystream);
+    xor(&mut right, keystream);
+
+    let result = drag_pair(&left, &right, "the ");
+
+    assert_eq!(result[0].offset, 7);
+    assert_eq!(result[0].plain_text, b" to ");
+}
@@ -1,8 +1,12 @@
 extern crate cryptopals;
 
+use std::fs::read_to_string;
+
 use super::super::utils::get_resources_folder;
 
-use cryptopals::crackers::xor::repeating_bytes::crack_file;
+use cryptopals::crackers::xor::repeating_bytes::{crack_file, crack_with_strategy};
+use cryptopals::detectors::xor::repeating_bytes::{detect_keysizes_with, Strategy};
+use cryptopals::text_scoring::Frequency;
 
 // Source: https://cryptopals.com/sets/1/challenges/6
 #[test]
@@ -15,3 +19,45 @@ fn test() {
 
     assert_eq!(result.key, expected_key);
 }
+
+#[test]
+fn strategies() {
+    let mut input_file = get_resources_folder(module_path!());
+    input_file.push("input.txt");
+    let input = base64::decode(read_to_string(input_file).unwrap().replace('\n', "")).unwrap();
+    let expected_key = "Terminator X: Bring the noise".as_bytes();
+
+    // Key sizes up to 60 so 58 is there to be collapsed into 29
+    let strategies = [
+        Strategy::HammingAllPairs { samples: 20 },
+        Strategy::IndexOfCoincidence,
+        Strategy::Kasiski,
+    ];
+    for &strategy in strategies.iter() {
+        let keysizes = detect_keysizes_with(strategy, &input, 2, 60, 3);
+        let result = crack_with_strategy(&Frequency, strategy, &input, 2, 60, 1);
+
+        assert_eq!(keysizes[0].size, expected_key.len());
+        assert!(keysizes.iter().all(|x| x.size != 58));
+        assert_eq!(result.key, expected_key);
+    }
+}
+
+#[test]
+fn short_input() {
+    let input = b"short ciphertext";
+    let strategies = [
+        Strategy::Hamming { samples: 5 },
+        Strategy::HammingAllPairs { samples: 1 },
+        Strategy::IndexOfCoincidence,
+        Strategy::Kasiski,
+    ];
+    for &strategy in strategies.iter() {
+        let keysizes = detect_keysizes_with(strategy, input, 2, 40, 3);
+
+        assert!(!keysizes.is_empty());
+        assert!(keysizes.iter().all(|x| x.size <= input.len() / 2));
+        assert!(keysizes.iter().all(|x| !x.score.is_nan()));
+    }
+    assert!(detect_keysizes_with(Strategy::IndexOfCoincidence, b"a", 2, 40, 3).is_empty());
+}
@@ -0,0 +1,23 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::aes::oracle_prefixing::crack;
+use cryptopals::oracles::aes::ecb::prefixing::Oracle;
+
+// Source: https://cryptopals.com/sets/2/challenges/14
+#[test]
+fn test() {
+    let input = "Um9sbGluJyBpbiBteSA1LjAKV2l0aCBteSByYWctdG9wIGRvd24gc28gbXkg
+aGFpciBjYW4gYmxvdwpUaGUgZ2lybGllcyBvbiBzdGFuZGJ5IHdhdmluZyBq
+dXN0IHRvIHNheSBoaQpEaWQgeW91IHN0b3A/IE5vLCBJIGp1c3QgZHJvdmUg
+YnkK"
+        .replace("\n", "");
+
+    let input_decoded = base64::decode(input).unwrap();
+
+    for _ in 0..5 {
+        let oracle = Oracle::new_with_hidden_prefix(&input_decoded);
+
+        let result = crack(&oracle).unwrap();
+        assert_eq!(result, input_decoded);
+    }
+}
@@ -2,3 +2,4 @@ mod challenge09;
 mod challenge10;
 mod challenge11;
 mod challenge12;
+mod challenge14;
@@ -0,0 +1,46 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::aes::padding_oracle::crack;
+use cryptopals::oracles::aes::cbc::padding::Oracle;
+
+// Source: https://cryptopals.com/sets/3/challenges/17
+#[test]
+fn test() {
+    let input = "MDAwMDAwTm93IHRoYXQgdGhlIHBhcnR5IGlzIGp1bXBpbmc=
+MDAwMDAxV2l0aCB0aGUgYmFzcyBraWNrZWQgaW4gYW5kIHRoZSBWZWdhJ3MgYXJlIHB1bXBpbic=
+MDAwMDAyUXVpY2sgdG8gdGhlIHBvaW50LCB0byB0aGUgcG9pbnQsIG5vIGZha2luZw==
+MDAwMDAzQ29va2luZyBNQydzIGxpa2UgYSBwb3VuZCBvZiBiYWNvbg==
+MDAwMDA0QnVybmluZyAnZW0sIGlmIHlvdSBhaW4ndCBxdWljayBhbmQgbmltYmxl
+MDAwMDA1SSBnbyBjcmF6eSB3aGVuIEkgaGVhciBhIGN5bWJhbA==
+MDAwMDA2QW5kIGEgaGlnaCBoYXQgd2l0aCBhIHNvdXBlZCB1cCB0ZW1wbw==
+MDAwMDA3SSdtIG9uIGEgcm9sbCwgaXQncyB0aW1lIHRvIGdvIHNvbG8=
+MDAwMDA4b2xsaW4nIGluIG15IGZpdmUgcG9pbnQgb2g=
+MDAwMDA5aXRoIG15IHJhZy10b3AgZG93biBzbyBteSBoYWlyIGNhbiBibG93";
+
+    let input_decoded: Vec<Vec<u8>> = input.lines().map(|x| base64::decode(x).unwrap()).collect();
+
+    for _ in 0..10 {
+        let oracle = Oracle::new(&input_decoded).unwrap();
+
+        let result = crack(&oracle, oracle.iv(), oracle.cipher_text()).unwrap();
+        assert!(input_decoded.contains(&result));
+    }
+}
+
+#[test]
+fn every_input() {
+    let input = [
+        "",
+        "YELLOW SUBMARINE",
+        "\x01",
+        "\x02\x02",
+        "Now that the party is jumping, quick to the point, to the point, no faking",
+    ];
+
+    for i in input.iter() {
+        let oracle = Oracle::new([i]).unwrap();
+
+        let result = crack(&oracle, oracle.iv(), oracle.cipher_text()).unwrap();
+        assert_eq!(result, i.as_bytes());
+    }
+}
@@ -0,0 +1,25 @@
+extern crate cryptopals;
+
+use std::str::from_utf8;
+
+use cryptopals::ciphers::aes::ctr::{crypt, Layout};
+
+// Source: https://cryptopals.com/sets/3/challenges/18
+#[test]
+fn test() {
+    let input = "L77na/nrFsKvynd6HzOoG7GHTLXsTVu9qvY/2syLXzhPweyyMTJULu/6/kXX0KSvoOLSFQ==";
+    let input_key = "YELLOW SUBMARINE";
+    let input_nonce = 0u64.to_le_bytes();
+    let expected = "Yo, VIP Let's kick it Ice, Ice, baby Ice, Ice, baby ";
+
+    let mut result = base64::decode(input).unwrap();
+    crypt(
+        &mut result,
+        input_key,
+        input_nonce,
+        Layout::Nonce64Counter64LE,
+    )
+    .unwrap();
+
+    assert_eq!(from_utf8(&result).unwrap(), expected);
+}
@@ -0,0 +1,49 @@
+extern crate cryptopals;
+
+use std::fs::read_to_string;
+
+use rand::prelude::*;
+
+use super::super::utils::get_resources_folder;
+
+use cryptopals::ciphers::aes::ctr::{crypt, Layout};
+use cryptopals::crackers::ctr::fixed_nonce::crack_truncated;
+
+// Source: https://cryptopals.com/sets/3/challenges/20
+#[test]
+fn test() {
+    let mut input_file = get_resources_folder(module_path!());
+    input_file.push("input.txt");
+    let input_key = random::<[u8; 16]>();
+    let input_nonce = [0; 8];
+
+    let input: Vec<Vec<u8>> = read_to_string(input_file)
+        .unwrap()
+        .lines()
+        .map(|x| base64::decode(x).unwrap())
+        .collect();
+
+    let cipher_texts: Vec<Vec<u8>> = input
+        .iter()
+        .map(|x| {
+            let mut output = x.clone();
+            crypt(
+                &mut output,
+                input_key,
+                input_nonce,
+                Layout::Nonce64Counter64LE,
+            )
+            .unwrap();
+            output
+        })
+        .collect();
+
+    let mut result = crack_truncated(&cipher_texts);
+    // The first column is all capitals, statistics alone get the case wrong
+    result.set_plain_text_byte(0, 0, b'I').unwrap();
+    assert!(result.set_plain_text_byte(0, 1000, b'I').is_err());
+    assert!(result.set_plain_text_byte(1000, 0, b'I').is_err());
+    for (plain_text, expected) in result.plain_texts.iter().zip(&input) {
+        assert_eq!(plain_text[..], expected[..plain_text.len()]);
+    }
+}
@@ -0,0 +1,34 @@
+extern crate cryptopals;
+
+use cryptopals::prng::mt19937::Mt19937;
+
+// Source: https://cryptopals.com/sets/3/challenges/21
+#[test]
+fn test() {
+    // Source: https://oeis.org/A221557
+    let expected = [
+        3499211612, 581869302, 3890346734, 3586334585, 545404204, 4161255391, 3922919429,
+        949333985, 2715962298, 1323567403, 418932835, 2350294565, 1196140740, 809094426,
+        2348838239, 4264392720, 4112460519, 4279768804, 4144164697, 4156218106, 676943009,
+        3117454609,
+    ];
+
+    let mut rng = Mt19937::new(5489);
+    for i in expected.iter() {
+        assert_eq!(rng.extract_number(), *i);
+    }
+}
+
+#[test]
+fn seed_1() {
+    // Source: https://github.com/google/gofountain/blob/master/mersenne_test.go
+    let expected = [
+        1791095845, 4282876139, 3093770124, 4005303368, 491263, 550290313, 1298508491, 4290846341,
+        630311759, 1013994432,
+    ];
+
+    let mut rng = Mt19937::new(1);
+    for i in expected.iter() {
+        assert_eq!(rng.extract_number(), *i);
+    }
+}
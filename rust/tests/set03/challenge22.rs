@@ -0,0 +1,26 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::prng::seed::{crack, crack_recent_timestamp};
+use cryptopals::oracles::prng::mt19937::timestamp_seeded_output;
+use cryptopals::prng::mt19937::Mt19937;
+
+// Source: https://cryptopals.com/sets/3/challenges/22
+#[test]
+fn test() {
+    let input = timestamp_seeded_output();
+
+    let seed = crack_recent_timestamp([input], 2000).unwrap();
+    assert_eq!(Mt19937::new(seed).extract_number(), input);
+}
+
+#[test]
+fn multiple_outputs() {
+    let input_seed = 1_591_315_200;
+    let mut rng = Mt19937::new(input_seed);
+    let input: Vec<u32> = (0..3).map(|_| rng.extract_number()).collect();
+
+    let result = crack(&input, input_seed - 500..=input_seed + 500).unwrap();
+    assert_eq!(result, input_seed);
+
+    assert!(crack(&input, 0..=1000).is_err());
+}
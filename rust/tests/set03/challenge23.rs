@@ -0,0 +1,48 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::crackers::prng::mt19937::{clone_from_outputs, clone_from_outputs_64};
+use cryptopals::prng::mt19937::{Mt19937, Mt19937_64};
+use cryptopals::Error;
+
+// Source: https://cryptopals.com/sets/3/challenges/23
+#[test]
+fn test() {
+    let mut orig = Mt19937::new(random());
+    let outputs: Vec<u32> = (0..Mt19937::STATE_SIZE)
+        .map(|_| orig.extract_number())
+        .collect();
+
+    let mut clone = clone_from_outputs(&outputs).unwrap();
+    for _ in 0..10000 {
+        assert_eq!(clone.next_u32(), orig.next_u32());
+    }
+}
+
+#[test]
+fn extra_outputs() {
+    let mut orig = Mt19937::new(random());
+    let outputs: Vec<u32> = (0..1000).map(|_| orig.next_u32()).collect();
+
+    let mut clone = clone_from_outputs(&outputs).unwrap();
+    for _ in 0..10000 {
+        assert_eq!(clone.next_u32(), orig.next_u32());
+    }
+
+    let result = clone_from_outputs(&outputs[..Mt19937::STATE_SIZE - 1]);
+    assert!(matches!(result, Err(Error::NotEnoughData(623))));
+}
+
+#[test]
+fn test_64() {
+    let mut orig = Mt19937_64::new(random());
+    let outputs: Vec<u64> = (0..Mt19937_64::STATE_SIZE)
+        .map(|_| orig.next_u64())
+        .collect();
+
+    let mut clone = clone_from_outputs_64(&outputs).unwrap();
+    for _ in 0..10000 {
+        assert_eq!(clone.next_u64(), orig.next_u64());
+    }
+}
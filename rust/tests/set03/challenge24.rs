@@ -0,0 +1,42 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::ciphers::mt19937::crypt;
+use cryptopals::crackers::prng::seed::{crack_stream_cipher, is_recent_timestamp_token};
+use cryptopals::oracles::prng::mt19937::{password_reset_token, stream_cipher};
+
+// Source: https://cryptopals.com/sets/3/challenges/24
+#[test]
+fn test() {
+    let input_suffix = b"AAAAAAAAAAAAAA";
+
+    let cipher_text = stream_cipher(input_suffix);
+    let key = crack_stream_cipher(&cipher_text, input_suffix).unwrap();
+
+    let mut result = cipher_text.clone();
+    crypt(&mut result, key as u32);
+    assert!(result.ends_with(input_suffix));
+}
+
+#[test]
+fn cipher() {
+    let mut rng = rand::thread_rng();
+    let input: Vec<u8> = (0..150).map(|_| rng.gen()).collect();
+    let input_key = rng.gen();
+
+    let mut result = input.clone();
+    crypt(&mut result, input_key);
+    assert_ne!(result, input);
+
+    crypt(&mut result, input_key);
+    assert_eq!(result, input);
+}
+
+#[test]
+fn password_token() {
+    let mut rng = rand::thread_rng();
+
+    assert!(is_recent_timestamp_token(password_reset_token(), 60));
+    assert!(!is_recent_timestamp_token(rng.gen::<[u8; 32]>(), 60));
+}
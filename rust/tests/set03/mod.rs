@@ -0,0 +1,8 @@
+mod challenge17;
+mod challenge18;
+mod challenge19;
+mod challenge20;
+mod challenge21;
+mod challenge22;
+mod challenge23;
+mod challenge24;
@@ -0,0 +1,48 @@
+extern crate cryptopals;
+
+use rand::prelude::*;
+
+use cryptopals::crackers::hash::length_extension::{crack, forge};
+use cryptopals::hashes::sha1::digest;
+
+const MESSAGE: &[u8] =
+    b"comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon";
+const SUFFIX: &[u8] = b";admin=true";
+
+fn mac(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut data = key.to_vec();
+    data.extend(message);
+    digest(data).to_vec()
+}
+
+// Source: https://cryptopals.com/sets/4/challenges/29
+#[test]
+fn test() {
+    let mut rng = thread_rng();
+    for _ in 0..10 {
+        let key: Vec<u8> = (0..rng.gen_range(1, 65)).map(|_| rng.gen()).collect();
+        let oracle = |message: &[u8], x: &[u8]| mac(&key, message) == x;
+
+        let result = crack(&oracle, MESSAGE, mac(&key, MESSAGE), SUFFIX, 1..=64).unwrap();
+        assert_eq!(result.key_size, key.len());
+        assert!(result.message.starts_with(MESSAGE));
+        assert!(result.message.ends_with(SUFFIX));
+        assert_eq!(result.mac, mac(&key, &result.message));
+    }
+}
+
+#[test]
+fn forge_known_key_size() {
+    let key = b"YELLOW SUBMARINE";
+
+    let result = forge(MESSAGE, mac(key, MESSAGE), SUFFIX, key.len()).unwrap();
+    assert_eq!(result.mac, mac(key, &result.message));
+}
+
+#[test]
+fn wrong_key_sizes() {
+    let key = b"YELLOW SUBMARINE";
+    let oracle = |message: &[u8], x: &[u8]| mac(key, message) == x;
+
+    assert!(crack(&oracle, MESSAGE, mac(key, MESSAGE), SUFFIX, 1..=15).is_err());
+}
@@ -0,0 +1,34 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::hash::length_extension::crack_with;
+use cryptopals::hashes::md4::Md4;
+use cryptopals::hashes::sha1::Sha1;
+use cryptopals::oracles::mac::Oracle;
+
+const MESSAGE: &[u8] =
+    b"comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon";
+const SUFFIX: &[u8] = b";admin=true";
+
+// Source: https://cryptopals.com/sets/4/challenges/30
+#[test]
+fn test() {
+    for _ in 0..10 {
+        let oracle = Oracle::<Md4>::new();
+
+        let result =
+            crack_with::<Md4, _, _, _, _>(&oracle, MESSAGE, oracle.mac(MESSAGE), SUFFIX, 1..=64)
+                .unwrap();
+        assert!(result.message.ends_with(SUFFIX));
+        assert!(oracle.verify(&result.message, &result.mac));
+    }
+}
+
+#[test]
+fn sha1_oracle() {
+    let oracle = Oracle::<Sha1>::new();
+
+    let result =
+        crack_with::<Sha1, _, _, _, _>(&oracle, MESSAGE, oracle.mac(MESSAGE), SUFFIX, 1..=64)
+            .unwrap();
+    assert!(oracle.verify(&result.message, &result.mac));
+}
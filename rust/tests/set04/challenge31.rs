@@ -0,0 +1,15 @@
+extern crate cryptopals;
+
+use std::time::Duration;
+
+use cryptopals::crackers::timing::hmac::crack;
+use cryptopals::oracles::timing::Oracle;
+
+// Source: https://cryptopals.com/sets/4/challenges/31
+#[test]
+fn test() {
+    let oracle = Oracle::with_mac_size(Duration::from_millis(10), 2);
+
+    let result = crack(&oracle, b"foo", oracle.mac_size(), 1).unwrap();
+    assert!(oracle.verify(b"foo", result));
+}
@@ -0,0 +1,23 @@
+extern crate cryptopals;
+
+use std::time::Duration;
+
+use cryptopals::crackers::timing::hmac::crack;
+use cryptopals::oracles::timing::Oracle;
+
+// Source: https://cryptopals.com/sets/4/challenges/32
+#[test]
+fn test() {
+    let oracle = Oracle::with_mac_size(Duration::from_millis(5), 3);
+
+    let result = crack(&oracle, b"foo", oracle.mac_size(), 3).unwrap();
+    assert!(oracle.verify(b"foo", result));
+}
+
+#[test]
+fn sub_millisecond() {
+    let oracle = Oracle::with_mac_size(Duration::from_micros(500), 3);
+
+    let result = crack(&oracle, b"foo", oracle.mac_size(), 5).unwrap();
+    assert!(oracle.verify(b"foo", result));
+}
@@ -0,0 +1,4 @@
+mod challenge29;
+mod challenge30;
+mod challenge31;
+mod challenge32;
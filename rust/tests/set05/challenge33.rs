@@ -0,0 +1,31 @@
+extern crate cryptopals;
+
+use cryptopals::math::bigint::BigUint;
+use cryptopals::protocols::dh::exchange::{KeyPair, Parameters};
+
+// Source: https://cryptopals.com/sets/5/challenges/33
+#[test]
+fn test() {
+    let parameters = Parameters::new(BigUint::from(37u32), BigUint::from(5u32));
+    for _ in 0..10 {
+        let alice = KeyPair::new(parameters.clone());
+        let bob = KeyPair::new(parameters.clone());
+
+        assert_eq!(
+            alice.shared_secret(bob.public_key()),
+            bob.shared_secret(alice.public_key())
+        );
+    }
+}
+
+#[test]
+fn nist() {
+    let alice = KeyPair::new(Parameters::nist());
+    let bob = KeyPair::new(Parameters::nist());
+
+    assert_eq!(Parameters::nist().p.bits(), 1536);
+    assert_eq!(
+        alice.session_key(bob.public_key()),
+        bob.session_key(alice.public_key())
+    );
+}
@@ -0,0 +1,44 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::dh::mitm::KeyFixing;
+use cryptopals::math::bigint::BigUint;
+use cryptopals::protocols::dh::echo::{run, run_with, Alice, Bob, Packet};
+use cryptopals::protocols::dh::exchange::Parameters;
+use cryptopals::Error;
+
+const MESSAGES: [&[u8]; 2] = [b"Super secret message!", b"YELLOW SUBMARINE"];
+
+#[test]
+fn echo() {
+    let mut alice = Alice::new(Parameters::nist(), MESSAGES);
+    let mut bob = Bob::new();
+
+    run(&mut alice, &mut bob).unwrap();
+    assert_eq!(bob.received, MESSAGES);
+    assert_eq!(alice.received, MESSAGES);
+}
+
+#[test]
+fn bad_parameters() {
+    let mut bob = Bob::new();
+    for &(p, g) in [(0u32, 2u32), (1, 1), (23, 24)].iter() {
+        let parameters = Parameters::new(BigUint::from(p), BigUint::from(g));
+        let result = bob.receive(Packet::Parameters(parameters));
+        assert!(matches!(result, Err(Error::Protocol(_))));
+    }
+}
+
+// Source: https://cryptopals.com/sets/5/challenges/34
+#[test]
+fn test() {
+    let mut alice = Alice::new(Parameters::nist(), MESSAGES);
+    let mut bob = Bob::new();
+    let mut mallory = KeyFixing::new();
+
+    run_with(&mut mallory, &mut alice, &mut bob).unwrap();
+    assert_eq!(alice.received, MESSAGES);
+    assert_eq!(
+        mallory.plain_texts,
+        [MESSAGES[0], MESSAGES[0], MESSAGES[1], MESSAGES[1]]
+    );
+}
@@ -0,0 +1,25 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::dh::mitm::{Generator, MaliciousGenerator};
+use cryptopals::protocols::dh::echo::{run_with, Alice, Bob};
+use cryptopals::protocols::dh::exchange::Parameters;
+
+const MESSAGES: [&[u8]; 2] = [b"Super secret message, Round 2ish!", b"YELLOW SUBMARINE"];
+
+// Source: https://cryptopals.com/sets/5/challenges/35
+#[test]
+fn test() {
+    for &generator in [Generator::One, Generator::P, Generator::PMinusOne].iter() {
+        let mut alice = Alice::new(Parameters::nist(), MESSAGES);
+        let mut bob = Bob::new();
+        let mut mallory = MaliciousGenerator::new(generator);
+
+        run_with(&mut mallory, &mut alice, &mut bob).unwrap();
+        assert_eq!(alice.received, MESSAGES);
+        assert_eq!(bob.received, MESSAGES);
+        assert_eq!(
+            mallory.plain_texts,
+            [MESSAGES[0], MESSAGES[0], MESSAGES[1], MESSAGES[1]]
+        );
+    }
+}
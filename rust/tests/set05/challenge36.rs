@@ -0,0 +1,59 @@
+extern crate cryptopals;
+
+use cryptopals::math::bigint::BigUint;
+use cryptopals::protocols::srp::exchange::{multiplier, Parameters, Verifiers};
+use cryptopals::protocols::srp::login::{run, Client, Packet, Server};
+
+const USERNAME: &str = "alice";
+const PASSWORD: &[u8] = b"correct horse battery staple";
+
+fn server() -> Server {
+    let mut verifiers = Verifiers::new(Parameters::nist());
+    verifiers.add(USERNAME, PASSWORD);
+    Server::new(verifiers)
+}
+
+// Source: https://cryptopals.com/sets/5/challenges/36
+#[test]
+fn test() {
+    let mut client = Client::new(Parameters::nist(), USERNAME, PASSWORD);
+    assert!(run(&mut client, &mut server()).unwrap());
+}
+
+#[test]
+fn wrong_password() {
+    let mut client = Client::new(Parameters::nist(), USERNAME, b"hunter2");
+    assert!(!run(&mut client, &mut server()).unwrap());
+}
+
+#[test]
+fn unknown_user() {
+    let mut client = Client::new(Parameters::nist(), "bob", PASSWORD);
+    assert!(!run(&mut client, &mut server()).unwrap());
+}
+
+#[test]
+fn stale_proof() {
+    let mut server = server();
+    let mut client = Client::new(Parameters::nist(), USERNAME, PASSWORD);
+    let challenge = server.respond(client.start()).unwrap();
+    let proof = client.receive(challenge).unwrap().unwrap();
+
+    let mut other = Client::new(Parameters::nist(), "bob", PASSWORD);
+    assert_eq!(server.respond(other.start()).unwrap(), Packet::Denied);
+    assert_eq!(server.respond(proof).unwrap(), Packet::Denied);
+}
+
+#[test]
+fn srp6_and_srp6a() {
+    let srp6a = Parameters::nist();
+    assert_eq!(srp6a.k, multiplier(&srp6a.n, &srp6a.g));
+    assert_ne!(srp6a.k, BigUint::from(3u32));
+
+    let srp6 = Parameters::nist_srp6();
+    assert_eq!(srp6.k, BigUint::from(3u32));
+    let mut verifiers = Verifiers::new(srp6.clone());
+    verifiers.add(USERNAME, PASSWORD);
+    let mut client = Client::new(srp6, USERNAME, PASSWORD);
+    assert!(run(&mut client, &mut Server::new(verifiers)).unwrap());
+}
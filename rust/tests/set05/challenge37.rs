@@ -0,0 +1,18 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::srp::zero_key::login;
+use cryptopals::protocols::srp::exchange::{Parameters, Verifiers};
+use cryptopals::protocols::srp::login::Server;
+
+// Source: https://cryptopals.com/sets/5/challenges/37
+#[test]
+fn test() {
+    let mut verifiers = Verifiers::new(Parameters::nist());
+    verifiers.add("alice", b"correct horse battery staple");
+    let mut server = Server::new(verifiers);
+
+    for multiple in 0..3 {
+        assert!(login(&mut server, &Parameters::nist(), "alice", multiple).unwrap());
+    }
+    assert!(!login(&mut server, &Parameters::nist(), "bob", 0).unwrap());
+}
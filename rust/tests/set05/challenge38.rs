@@ -0,0 +1,51 @@
+extern crate cryptopals;
+
+use super::super::utils::get_resources_folder;
+
+use cryptopals::crackers::srp::dictionary::{crack_file, FakeServer};
+use cryptopals::protocols::srp::exchange::{Parameters, Verifiers};
+use cryptopals::protocols::srp::login::{run, Client, Server};
+
+const USERNAME: &str = "alice";
+const PASSWORD: &[u8] = b"sunshine";
+
+#[test]
+fn simple_login() {
+    let mut verifiers = Verifiers::new(Parameters::nist());
+    verifiers.add(USERNAME, PASSWORD);
+    let mut server = Server::simple(verifiers);
+
+    let mut client = Client::new(Parameters::nist(), USERNAME, PASSWORD);
+    assert!(run(&mut client, &mut server).unwrap());
+    let mut client = Client::new(Parameters::nist(), USERNAME, b"password");
+    assert!(!run(&mut client, &mut server).unwrap());
+}
+
+// Source: https://cryptopals.com/sets/5/challenges/38
+#[test]
+fn test() {
+    let mut input_file = get_resources_folder(module_path!());
+    input_file.push("words.txt");
+
+    let mut server = FakeServer::new(Parameters::nist());
+    let mut client = Client::new(Parameters::nist(), USERNAME, PASSWORD);
+    assert!(!run(&mut client, &mut server).unwrap());
+    assert_eq!(server.username.as_deref(), Some(USERNAME));
+
+    assert_eq!(crack_file(&server, input_file).unwrap(), PASSWORD);
+}
+
+#[test]
+fn crlf_word_list() {
+    let mut input_file = std::env::temp_dir();
+    input_file.push("cryptopals_challenge38_crlf.txt");
+    std::fs::write(&input_file, b"\r\npassword\r\n\r\nsunshine\r\n").unwrap();
+
+    let mut server = FakeServer::new(Parameters::nist());
+    let mut client = Client::new(Parameters::nist(), USERNAME, PASSWORD);
+    assert!(!run(&mut client, &mut server).unwrap());
+
+    let output = crack_file(&server, &input_file);
+    std::fs::remove_file(&input_file).unwrap();
+    assert_eq!(output.unwrap(), PASSWORD);
+}
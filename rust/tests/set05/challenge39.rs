@@ -0,0 +1,14 @@
+extern crate cryptopals;
+
+use cryptopals::math::bigint::{from_bytes_be, to_bytes_be};
+use cryptopals::pubkey::rsa::PrivateKey;
+
+// Source: https://cryptopals.com/sets/5/challenges/39
+#[test]
+fn test() {
+    let plain_text = b"Super secret message!";
+    let key = PrivateKey::new(512, 3).unwrap();
+
+    let cipher_text = key.public_key().encrypt(&from_bytes_be(plain_text));
+    assert_eq!(to_bytes_be(&key.decrypt(&cipher_text)), plain_text);
+}
@@ -0,0 +1,23 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::rsa::broadcast::crack;
+use cryptopals::math::bigint::{from_bytes_be, to_bytes_be};
+use cryptopals::pubkey::rsa::PrivateKey;
+
+const PLAIN_TEXT: &[u8] = b"Super secret message!";
+
+// Source: https://cryptopals.com/sets/5/challenges/40
+#[test]
+fn test() {
+    let message = from_bytes_be(PLAIN_TEXT);
+    let pairs: Vec<_> = (0..3)
+        .map(|_| {
+            let key = PrivateKey::new(512, 3).unwrap();
+            let cipher_text = key.encrypt(&message);
+            (key.public_key().clone(), cipher_text)
+        })
+        .collect();
+
+    assert_eq!(to_bytes_be(&crack(&pairs).unwrap()), PLAIN_TEXT);
+    assert!(crack(&pairs[..2]).is_err());
+}
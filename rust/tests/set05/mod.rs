@@ -0,0 +1,8 @@
+mod challenge33;
+mod challenge34;
+mod challenge35;
+mod challenge36;
+mod challenge37;
+mod challenge38;
+mod challenge39;
+mod challenge40;
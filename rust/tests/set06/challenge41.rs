@@ -0,0 +1,33 @@
+extern crate cryptopals;
+
+use cryptopals::crackers::rsa::unpadded_oracle::crack;
+use cryptopals::math::bigint::BigUint;
+use cryptopals::math::bigint::{from_bytes_be, to_bytes_be};
+use cryptopals::oracles::rsa::Oracle;
+use cryptopals::pubkey::rsa::PublicKey;
+use cryptopals::Error;
+
+const PLAIN_TEXT: &[u8] = b"{time: 1356304276, social: '555-55-5555'}";
+
+// Source: https://cryptopals.com/sets/6/challenges/41
+#[test]
+fn test() {
+    let oracle = Oracle::new(512).unwrap();
+    let public_key = oracle.public_key().clone();
+    let cipher_text = public_key.encrypt(&from_bytes_be(PLAIN_TEXT));
+
+    // Already decrypted once for whoever sent it
+    assert!(oracle.decrypt(&cipher_text).is_some());
+    assert!(oracle.decrypt(&cipher_text).is_none());
+
+    let plain_text = crack(&oracle, &public_key, &cipher_text).unwrap();
+    assert_eq!(to_bytes_be(&plain_text), PLAIN_TEXT);
+}
+
+#[test]
+fn tiny_modulus() {
+    let oracle = |x: &BigUint| Some(x.clone());
+    let public_key = PublicKey::new(BigUint::from(2u32), BigUint::from(3u32));
+    let result = crack(&oracle, &public_key, &BigUint::from(1u32));
+    assert!(matches!(result, Err(Error::InvalidKey(_))));
+}
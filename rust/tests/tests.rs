@@ -3,3 +3,7 @@ mod utils;
 
 mod set01;
 mod set02;
+mod set03;
+mod set04;
+mod set05;
+mod set06;